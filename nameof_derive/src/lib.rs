@@ -0,0 +1,354 @@
+//!
+//! Rust name_of! Macro
+//!
+//! MIT License
+//! Copyright (c) 2018 SilentByte <https://silentbyte.com/>
+//!
+
+#![crate_name = "nameof_derive"]
+
+extern crate proc_macro;
+
+use proc_macro::{Delimiter, Spacing, TokenStream, TokenTree};
+
+/// Derives `nameof::FieldNames` for a struct, which provides the names of all its fields
+/// as the associated constant `FIELD_NAMES`, e.g. `["x", "y"]` for `struct Point { x: i32, y: i32 }`.
+///
+/// Fields of tuple structs are named by their index, e.g. `["0", "1"]`, and unit structs
+/// have no field names at all. Raw identifiers are named without their `r#` prefix.
+/// Deriving the trait for enums or unions causes a compilation error.
+#[proc_macro_derive(FieldNames)]
+pub fn derive_field_names(input: TokenStream) -> TokenStream {
+    derive(input, "FieldNames", |s| {
+        let fields: Vec<String> = s.fields.iter().map(|f| format!("{:?}", f.name)).collect();
+        Ok(format!(
+            "const FIELD_NAMES: &'static [&'static str] = &[{}];",
+            fields.join(", ")
+        ))
+    })
+}
+
+/// Derives `nameof::SerdeFieldNames` for a struct, which provides pairs of the names of all its fields
+/// and the names under which they are serialized as the associated constant `SERDE_FIELD_NAMES`,
+/// e.g. `[("x", "pos_x"), ("y", "y")]` if the field `x` is annotated with `#[serde(rename = "pos_x")]`.
+///
+/// Fields are renamed by `#[serde(rename = "...")]` and by the `serialize` name of
+/// `#[serde(rename(serialize = "...", deserialize = "..."))]`. Other fields are renamed according to
+/// `#[serde(rename_all = "...")]` of the struct, which accepts the same rules as serde, e.g. `"camelCase"`.
+/// Deriving the trait for enums or unions causes a compilation error, and so does an unknown rule.
+#[proc_macro_derive(SerdeFieldNames, attributes(serde))]
+pub fn derive_serde_field_names(input: TokenStream) -> TokenStream {
+    derive(input, "SerdeFieldNames", |s| {
+        let rename_all = serde_argument(&s.attributes, "rename_all");
+        let mut fields = Vec::new();
+        for f in &s.fields {
+            fields.push(match (&f.rename, &rename_all) {
+                (Some(rename), _) => format!("({:?}, {})", f.name, rename),
+                (None, Some(rule)) => format!("({:?}, {:?})", f.name, rename_field(&f.name, rule)?),
+                (None, None) => format!("({:?}, {:?})", f.name, f.name),
+            });
+        }
+        Ok(format!(
+            "const SERDE_FIELD_NAMES: &'static [(&'static str, &'static str)] = &[{}];",
+            fields.join(", ")
+        ))
+    })
+}
+
+struct Struct {
+    attributes: Vec<TokenTree>,
+    name: String,
+    impl_generics: Vec<String>,
+    type_generics: Vec<String>,
+    where_clause: String,
+    fields: Vec<Field>,
+}
+
+struct Field {
+    name: String,
+    rename: Option<String>,
+}
+
+fn derive(
+    input: TokenStream,
+    trait_name: &str,
+    body: impl Fn(&Struct) -> Result<String, &'static str>,
+) -> TokenStream {
+    match parse_struct(input).and_then(|s| Ok((body(&s)?, s))) {
+        Ok((body, s)) => format!(
+            "impl<{}> ::nameof::{} for {}<{}> {} {{ {} }}",
+            s.impl_generics.join(", "),
+            trait_name,
+            s.name,
+            s.type_generics.join(", "),
+            s.where_clause,
+            body,
+        ),
+        Err(message) => format!("compile_error!({:?});", message),
+    }
+    .parse()
+    .unwrap()
+}
+
+fn parse_struct(input: TokenStream) -> Result<Struct, &'static str> {
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    let mut i = skip_attributes_and_visibility(&tokens, 0);
+    let attributes = tokens[..i].to_vec();
+
+    match tokens.get(i) {
+        Some(TokenTree::Ident(ident)) if ident.to_string() == "struct" => i += 1,
+        _ => return Err("field names can only be derived for structs"),
+    }
+
+    let name = match tokens.get(i) {
+        Some(TokenTree::Ident(ident)) => ident.to_string(),
+        _ => return Err("expected the name of the struct"),
+    };
+    i += 1;
+
+    let mut impl_generics = Vec::new();
+    let mut type_generics = Vec::new();
+
+    if is_punct(tokens.get(i), '<') {
+        let end = find_closing_angle_bracket(&tokens, i)?;
+        for param in split_top_level(&tokens[i + 1..end], ',') {
+            impl_generics.push(stream_to_string(strip_default(param)));
+            type_generics.push(generic_argument(param)?);
+        }
+        i = end + 1;
+    }
+
+    let mut where_clause = Vec::new();
+    let mut fields = Vec::new();
+
+    while i < tokens.len() {
+        match &tokens[i] {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                let body: Vec<TokenTree> = group.stream().into_iter().collect();
+                for field in split_top_level(&body, ',') {
+                    let j = skip_attributes_and_visibility(field, 0);
+                    match field.get(j) {
+                        Some(TokenTree::Ident(ident)) => fields.push(Field {
+                            name: ident.to_string().trim_start_matches("r#").to_string(),
+                            rename: serde_argument(&field[..j], "rename"),
+                        }),
+                        _ => return Err("expected the name of a field"),
+                    }
+                }
+                break;
+            }
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => {
+                let body: Vec<TokenTree> = group.stream().into_iter().collect();
+                let count = split_top_level(&body, ',').len();
+                fields.extend((0..count).map(|index| Field {
+                    name: index.to_string(),
+                    rename: None,
+                }));
+            }
+            TokenTree::Punct(punct) if punct.as_char() == ';' => break,
+            token => where_clause.push(token.clone()),
+        }
+        i += 1;
+    }
+
+    Ok(Struct {
+        attributes,
+        name,
+        impl_generics,
+        type_generics,
+        where_clause: stream_to_string(&where_clause),
+        fields,
+    })
+}
+
+fn skip_attributes_and_visibility(tokens: &[TokenTree], mut i: usize) -> usize {
+    while is_punct(tokens.get(i), '#') {
+        i += 2;
+    }
+
+    if let Some(TokenTree::Ident(ident)) = tokens.get(i) {
+        if ident.to_string() == "pub" {
+            i += 1;
+            if let Some(TokenTree::Group(group)) = tokens.get(i) {
+                if group.delimiter() == Delimiter::Parenthesis {
+                    i += 1;
+                }
+            }
+        }
+    }
+
+    i
+}
+
+/// Returns the literal given to a serde attribute as `key = "..."`, or as `key(serialize = "...")`.
+fn serde_argument(attributes: &[TokenTree], key: &str) -> Option<String> {
+    for token in attributes {
+        let attribute: Vec<TokenTree> = match token {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Bracket => {
+                group.stream().into_iter().collect()
+            }
+            _ => continue,
+        };
+
+        let arguments: Vec<TokenTree> = match attribute.as_slice() {
+            [TokenTree::Ident(ident), TokenTree::Group(group)]
+                if ident.to_string() == "serde" && group.delimiter() == Delimiter::Parenthesis =>
+            {
+                group.stream().into_iter().collect()
+            }
+            _ => continue,
+        };
+
+        for argument in split_top_level(&arguments, ',') {
+            match argument {
+                [TokenTree::Ident(name), TokenTree::Punct(eq), TokenTree::Literal(value)]
+                    if name.to_string() == key && eq.as_char() == '=' =>
+                {
+                    return Some(value.to_string());
+                }
+                [TokenTree::Ident(name), TokenTree::Group(group)]
+                    if name.to_string() == key && group.delimiter() == Delimiter::Parenthesis =>
+                {
+                    let names: Vec<TokenTree> = group.stream().into_iter().collect();
+                    for name in split_top_level(&names, ',') {
+                        if let [TokenTree::Ident(side), TokenTree::Punct(eq), TokenTree::Literal(value)] =
+                            name
+                        {
+                            if side.to_string() == "serialize" && eq.as_char() == '=' {
+                                return Some(value.to_string());
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    None
+}
+
+/// Renames a field according to a rule of `#[serde(rename_all = "...")]`, in the same way as serde.
+fn rename_field(field: &str, rule: &str) -> Result<String, &'static str> {
+    let pascal_case = || {
+        let mut pascal = String::new();
+        let mut capitalize = true;
+        for c in field.chars() {
+            if c == '_' {
+                capitalize = true;
+            } else if capitalize {
+                pascal.push(c.to_ascii_uppercase());
+                capitalize = false;
+            } else {
+                pascal.push(c);
+            }
+        }
+        pascal
+    };
+
+    match rule
+        .trim_start_matches('r')
+        .trim_matches('#')
+        .trim_matches('"')
+    {
+        "lowercase" | "snake_case" => Ok(field.to_string()),
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => Ok(field.to_ascii_uppercase()),
+        "PascalCase" => Ok(pascal_case()),
+        "camelCase" => {
+            let pascal = pascal_case();
+            let mut chars = pascal.chars();
+            Ok(match chars.next() {
+                Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                None => pascal,
+            })
+        }
+        "kebab-case" => Ok(field.replace('_', "-")),
+        "SCREAMING-KEBAB-CASE" => Ok(field.to_ascii_uppercase().replace('_', "-")),
+        _ => Err("unknown rule in `#[serde(rename_all = \"...\")]`"),
+    }
+}
+
+fn find_closing_angle_bracket(tokens: &[TokenTree], start: usize) -> Result<usize, &'static str> {
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate().skip(start) {
+        depth += angle_bracket_depth_change(tokens, i, token);
+        if depth == 0 {
+            return Ok(i);
+        }
+    }
+
+    Err("expected `>` after the generic parameters")
+}
+
+fn angle_bracket_depth_change(tokens: &[TokenTree], i: usize, token: &TokenTree) -> i32 {
+    match token {
+        TokenTree::Punct(punct) if punct.as_char() == '<' => 1,
+        TokenTree::Punct(punct) if punct.as_char() == '>' => {
+            // The `>` of `->` in bounds such as `F: Fn() -> u8` does not close a bracket.
+            match i.checked_sub(1).and_then(|p| tokens.get(p)) {
+                Some(TokenTree::Punct(prev))
+                    if prev.as_char() == '-' && prev.spacing() == Spacing::Joint =>
+                {
+                    0
+                }
+                _ => -1,
+            }
+        }
+        _ => 0,
+    }
+}
+
+fn split_top_level(tokens: &[TokenTree], separator: char) -> Vec<&[TokenTree]> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (i, token) in tokens.iter().enumerate() {
+        depth += angle_bracket_depth_change(tokens, i, token);
+        if depth == 0 && is_punct(Some(token), separator) {
+            parts.push(&tokens[start..i]);
+            start = i + 1;
+        }
+    }
+
+    if start < tokens.len() {
+        parts.push(&tokens[start..]);
+    }
+
+    parts
+}
+
+fn strip_default(param: &[TokenTree]) -> &[TokenTree] {
+    split_top_level(param, '=')
+        .first()
+        .copied()
+        .unwrap_or(param)
+}
+
+fn generic_argument(param: &[TokenTree]) -> Result<String, &'static str> {
+    match param {
+        [TokenTree::Punct(apostrophe), TokenTree::Ident(lifetime), ..]
+            if apostrophe.as_char() == '\'' =>
+        {
+            Ok(format!("'{}", lifetime))
+        }
+        [TokenTree::Ident(keyword), TokenTree::Ident(name), ..]
+            if keyword.to_string() == "const" =>
+        {
+            Ok(name.to_string())
+        }
+        [TokenTree::Ident(name), ..] => Ok(name.to_string()),
+        _ => Err("expected a generic parameter"),
+    }
+}
+
+fn is_punct(token: Option<&TokenTree>, c: char) -> bool {
+    match token {
+        Some(TokenTree::Punct(punct)) => punct.as_char() == c,
+        _ => false,
+    }
+}
+
+fn stream_to_string(tokens: &[TokenTree]) -> String {
+    tokens.iter().cloned().collect::<TokenStream>().to_string()
+}
@@ -0,0 +1,52 @@
+use nameof::{all_field_names_of, FieldNames};
+
+#[derive(FieldNames)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(FieldNames)]
+#[allow(dead_code)]
+pub struct Config<'a, T: Clone + Default = u8, const N: usize = 4>
+where
+    T: std::fmt::Debug,
+{
+    /// The name of the configuration.
+    pub name: &'a str,
+    pub(crate) values: std::collections::HashMap<String, [T; N]>,
+    r#type: Option<Box<dyn Fn() -> T>>,
+}
+
+#[derive(FieldNames)]
+#[allow(dead_code)]
+struct Pair(i32, pub String);
+
+#[derive(FieldNames)]
+struct Unit;
+
+#[test]
+fn derive_field_names_works() {
+    assert_eq!(["x", "y"], Point::FIELD_NAMES);
+    assert_eq!(["x", "y"], all_field_names_of!(Point));
+    assert_eq!(Point { x: 1, y: 2 }.x + Point { x: 1, y: 2 }.y, 3);
+}
+
+#[test]
+fn derive_field_names_for_generic_struct_works() {
+    assert_eq!(
+        ["name", "values", "type"],
+        all_field_names_of!(Config<'static, u8, 4>)
+    );
+}
+
+#[test]
+fn derive_field_names_for_tuple_struct_works() {
+    assert_eq!(["0", "1"], all_field_names_of!(Pair));
+}
+
+#[test]
+fn derive_field_names_for_unit_struct_works() {
+    assert!(all_field_names_of!(Unit).is_empty());
+    let _ = Unit;
+}
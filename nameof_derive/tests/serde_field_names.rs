@@ -0,0 +1,126 @@
+use nameof::{name_of, serde_name_of, FieldNames, SerdeFieldNames};
+
+#[derive(FieldNames, SerdeFieldNames)]
+#[allow(dead_code)]
+struct User {
+    #[serde(rename = "userName")]
+    name: String,
+    /// The age of the user.
+    #[serde(default, rename = "userAge")]
+    age: u8,
+    email: String,
+    #[serde(rename = r"type")]
+    r#kind: u8,
+}
+
+#[derive(SerdeFieldNames)]
+#[allow(dead_code)]
+struct Wrapper<T> {
+    #[serde(rename = "inner_value")]
+    value: T,
+}
+
+#[derive(SerdeFieldNames)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+struct Account {
+    user_id: u64,
+    display_name: String,
+    #[serde(rename = "mail")]
+    email_address: String,
+    #[serde(rename(serialize = "createdAt", deserialize = "created"))]
+    created_at: u64,
+    #[serde(rename(deserialize = "modified"))]
+    modified_at: u64,
+}
+
+#[derive(SerdeFieldNames)]
+#[serde(deny_unknown_fields, rename_all(serialize = "SCREAMING-KEBAB-CASE"))]
+#[allow(dead_code)]
+struct Header {
+    content_type: String,
+    r#type: u8,
+}
+
+#[derive(SerdeFieldNames)]
+#[serde(rename_all = "PascalCase")]
+#[allow(dead_code)]
+struct Point {
+    x: i32,
+    y_offset: i32,
+}
+
+#[test]
+fn serde_name_of_renamed_field_works() {
+    assert_eq!("userName", serde_name_of!(name in User));
+    assert_eq!("name", name_of!(name in User));
+    assert_eq!("userAge", serde_name_of!(age in User));
+    assert_eq!("age", name_of!(age in User));
+}
+
+#[test]
+fn serde_name_of_field_without_rename_works() {
+    assert_eq!("email", serde_name_of!(email in User));
+}
+
+#[test]
+fn serde_name_of_raw_identifier_field_works() {
+    assert_eq!("type", serde_name_of!(r#kind in User));
+}
+
+#[test]
+fn serde_name_of_generic_struct_field_works() {
+    assert_eq!("inner_value", serde_name_of!(value in Wrapper<u8>));
+}
+
+#[test]
+fn derive_serde_field_names_works() {
+    assert_eq!(
+        [
+            ("name", "userName"),
+            ("age", "userAge"),
+            ("email", "email"),
+            ("kind", "type"),
+        ],
+        User::SERDE_FIELD_NAMES
+    );
+    assert_eq!(["name", "age", "email", "kind"], User::FIELD_NAMES);
+}
+
+#[test]
+fn serde_name_of_rename_all_works() {
+    assert_eq!("userId", serde_name_of!(user_id in Account));
+    assert_eq!("displayName", serde_name_of!(display_name in Account));
+    assert_eq!("CONTENT-TYPE", serde_name_of!(content_type in Header));
+    assert_eq!("TYPE", serde_name_of!(r#type in Header));
+    assert_eq!("YOffset", serde_name_of!(y_offset in Point));
+}
+
+#[test]
+fn serde_name_of_field_rename_overrides_rename_all() {
+    assert_eq!("mail", serde_name_of!(email_address in Account));
+}
+
+#[test]
+fn serde_name_of_serialize_rename_works() {
+    assert_eq!("createdAt", serde_name_of!(created_at in Account));
+    assert_eq!("modifiedAt", serde_name_of!(modified_at in Account));
+}
+
+#[test]
+fn derive_serde_field_names_with_rename_all_works() {
+    assert_eq!(
+        [
+            ("user_id", "userId"),
+            ("display_name", "displayName"),
+            ("email_address", "mail"),
+            ("created_at", "createdAt"),
+            ("modified_at", "modifiedAt"),
+        ],
+        Account::SERDE_FIELD_NAMES
+    );
+    assert_eq!(
+        [("x", "X"), ("y_offset", "YOffset")],
+        Point::SERDE_FIELD_NAMES
+    );
+}
@@ -6,6 +6,22 @@
 //!
 
 #![crate_name = "nameof"]
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(test)]
+extern crate std;
+
+#[cfg(any(feature = "derive", feature = "serde"))]
+extern crate nameof_derive;
+
+#[cfg(feature = "derive")]
+pub use nameof_derive::FieldNames;
+
+#[cfg(feature = "serde")]
+pub use nameof_derive::SerdeFieldNames;
 
 /// Takes a binding, type, const, or function as an argument and returns its
 /// unqualified string representation. If the identifier does not exist
@@ -16,14 +32,116 @@
 /// The syntax depends on the type of the identifier:
 ///
 /// 1. Bindings to variables and functions require no annotation,
-///    e.g. `name_of!(some_binding)`.
+///    e.g. `name_of!(some_binding)`. This includes bindings introduced by destructuring,
+///    e.g. both `name_of!(a)` and `name_of!(b)` after `let (a, b) = (1, 2);`. The keyword `ref` may be used to
+///    validate the binding by taking a shared reference, e.g. `name_of!(ref some_binding)`.
+///    If the binding is currently borrowed mutably, use the keyword `mut` instead,
+///    e.g. `name_of!(mut some_binding)`, which validates the binding without borrowing it.
+///
+///    Static items may be annotated with the keyword `static`, e.g. `name_of!(static SOME_STATIC)`,
+///    which validates the item by its address only and thus never reads mutable statics.
+///
+/// 2. Functions may optionally be annotated with the keyword `fn`, e.g. `name_of!(fn some_function)`,
+///    in which case the identifier must refer to a callable item or binding.
+///    This disambiguates functions from types that share the same name.
+///    Generic functions may be specified using the turbofish syntax, e.g. `name_of!(some_function::<u8>)`,
+///    in which case only the name of the function is returned.
+///
+///    Methods and associated functions are referred to with both the `fn` and `in` keywords,
+///    e.g. `name_of!(fn some_method in SomeType)` or `name_of!(fn new in Vec<u8>)`.
+///    Methods provided by traits in scope are supported as well. The `fn` keyword is required,
+///    since `name_of!(new in Vec<u8>)` refers to a field. Both cannot be validated by the same form,
+///    because falling back from fields to associated items would make fields ambiguous whenever a trait
+///    in scope provides an item of the same name, e.g. a field `from` and the blanket implementation of `From`.
+///    Generic methods may be specified using the turbofish syntax, e.g. `name_of!(fn some_method::<u8> in SomeType)`.
+///    This is required for methods with type parameters, e.g. `name_of!(fn collect::<Vec<u8>> in std::vec::IntoIter<u8>)`,
+///    since validation refers to the method as a function item, whose type parameters cannot be inferred without a call.
+///    Omitting them, e.g. `name_of!(fn collect in std::vec::IntoIter<u8>)`, causes a compilation error
+///    asking for type annotations. Any type arguments that satisfy the bounds of the method may be given,
+///    as they only serve validation and do not affect the result.
+///    Alternatively, methods may be referred to by their path, e.g. `name_of!(SomeType::some_method)`,
+///    in which case only the last segment of the path is returned. Since the type parameters of generic
+///    methods and types cannot be inferred in this form, use `name_of!(fn push in Vec<u8>)` for those instead.
+///
+/// 3. Types and structs require the keyword `type`, e.g. `name_of!(type SomeType)`.
+///    Alternatively, the macro `name_of_type!(SomeType)` may be used. Types referred to by an absolute path,
+///    e.g. in other crates, are returned without their path, e.g. `name_of!(type ::std::vec::Vec<u8>)` returns `"Vec<u8>"`.
+///    Likewise, functions and constants may be referred to by an absolute path, e.g. `name_of!(::std::process::abort)`.
+///    Generic arguments may be inferred, e.g. `name_of!(type Vec<_>)` returns `"Vec<_>"`.
+///
+///    Generic type parameters may be annotated with the keyword `type_param`, e.g. `name_of!(type_param T)`
+///    within a generic function or impl, which returns `"T"`. Unsized parameters such as `T: ?Sized` are supported.
+///    The identifier is validated as a type in scope, so a concrete type such as `String` is accepted as well;
+///    the keyword documents intent rather than proving that the type is a generic parameter.
+///    Const generic parameters are annotated with the keyword `const_param` instead, e.g. `name_of!(const_param N)`,
+///    which causes a compilation error if the identifier cannot be used in constant expressions, such as a local
+///    binding. Like `type_param`, this does not prove that the identifier is a generic parameter,
+///    so constants and statics in scope are accepted as well.
+///
+/// 4. Traits require the keyword `trait`, e.g. `name_of!(trait SomeTrait)`.
+///    The generic arguments of traits may refer to generic parameters in scope, e.g. `name_of!(trait From<T>)`.
+///    Associated types of traits are referred to with the `type` and `in` keywords,
+///    e.g. `name_of!(type Item in Iterator)` or `name_of!(type Output in Add<T>)`.
+///
+/// 5. Modules require the keyword `mod`, e.g. `name_of!(mod some_module)`
+///    or `name_of!(mod crate::some_module)`. Only the last segment of the path is returned.
+///
+/// 6. Fields within structs and unions are referred to with the `in` keyword,
+///    e.g. `name_of!(some_field in SomeType)`. Nested fields are separated by dots,
+///    e.g. `name_of!(some_field.nested_field in SomeType)`, which returns `"some_field.nested_field"`.
+///    Fields of tuple structs are referred to by their index, e.g. `name_of!(0 in SomeTupleStruct)`.
+///    Fields may also be referred to through reference types, e.g. `name_of!(some_field in &SomeType)`,
+///    and through types implementing `Deref`, e.g. `name_of!(some_field in Box<SomeType>)`,
+///    unless the dereferencing type has a field of the same name itself.
+///    The same applies to fields of enum variants, e.g. `name_of!(some_field in SomeEnum::SomeVariant)`
+///    or `name_of!(0 in SomeEnum::SomeVariant)`. Since such paths are validated by pattern matching,
+///    unions must not be referred to by a path of exactly two segments, e.g. use `self::module::SomeUnion`.
+///
+///    Within methods, fields of `self` may be referred to directly, e.g. `name_of!(self.some_field)`
+///    or `name_of!(self.0)`, which returns `"some_field"` or `"0"` without repeating the type.
+///    The same works for fields of any other binding in scope, e.g. `name_of!(some_binding.some_field)`.
+///
+/// 7. Constants require the keyword `const`, e.g. `name_of!(const SOME_CONST)`, which causes a compilation
+///    error if the identifier cannot be used in constant expressions, such as a local binding.
+///    Note that this only ensures the name is usable at compile time, so functions and statics are accepted as well.
+///    Associated constants are referred to with the `const` and `in` keywords,
+///    e.g. `name_of!(const SOME_CONST in SomeType)` or `name_of!(const SOME_CONST in SomeGenericType<u8>)`.
+///    Associated constants declared by traits are referred to with the additional keyword `trait`,
+///    e.g. `name_of!(const SOME_CONST in trait SomeTrait)`. The generic arguments of such traits may refer to
+///    generic parameters in scope, e.g. `name_of!(const SOME_CONST in trait SomeTrait<T>)`,
+///    unless the trait places bounds on its parameters.
 ///
-/// 2. Types and structs require the keyword `type`, e.g. `name_of!(type SomeType)`.
-///    Alternatively, the macro `name_of_type!(SomeType)` may be used.
+/// 8. Lifetimes require the keyword `lifetime`, e.g. `name_of!(lifetime 'a)`, which returns `"'a"`
+///    and causes a compilation error if the lifetime has not been declared in the current scope.
 ///
-/// 3. Fields within structs are referred to with the `in` keyword,
-///    e.g. `name_of!(some_field in SomeType)`.
+/// 9. Macros require the keyword `macro`, e.g. `name_of!(macro some_macro)` or `name_of!(macro some_macro!)`.
+///    Since macros cannot be referred to without being invoked, their existence is not validated.
 ///
+/// The name of a binding or function can be converted into a different case by specifying
+/// `snake`, `kebab`, or `screaming`, e.g. `name_of!(snake someBinding)` returns `"some_binding"`,
+/// `name_of!(kebab someBinding)` returns `"some-binding"`, and `name_of!(screaming someBinding)`
+/// returns `"SOME_BINDING"`. The conversion is performed at compile time without allocation.
+/// Only ASCII letters are converted, while all other characters are retained unchanged
+/// and never start a new word, e.g. `name_of!(screaming café)` returns `"CAFé"`.
+/// Leading underscores are retained, e.g. `name_of!(screaming _unused)` returns `"_UNUSED"`.
+///
+/// Functions and methods must not be called within the macro. Writing a call such as `name_of!(some_function())`
+/// causes a compilation error that explains how to refer to the function or method instead.
+///
+/// Raw identifiers are returned without their `r#` prefix, e.g. `name_of!(r#type)` returns `"type"`.
+///
+/// Since the result is always a `&'static str`, the macro may also be used to
+/// initialize constants and statics, e.g. `const NAME: &str = name_of!(some_function);`.
+///
+/// When wrapping this macro in another `macro_rules!` macro, forward identifiers as `ident` or `tt`
+/// fragments, e.g. `($x: ident) => { name_of!($x) }`, and types as `ty` fragments together with
+/// the keyword `type`. Fragments such as `expr` are opaque to this macro and cannot be matched.
+///
+/// Validation is performed within `if false` branches that are never taken. Fields, for instance,
+/// are validated by taking their address with `addr_of!` on a placeholder value of the given type,
+/// which neither reads nor moves it. The branch is type-checked but produces no runtime code,
+/// and as it creates no closures, using the macro many times within the same function stays cheap
+/// to compile as well.
 ///
 /// # Examples
 ///
@@ -78,42 +196,475 @@
 ///
 /// # }
 /// ```
+///
+/// Using the `fn` keyword on an identifier that is not callable causes a compilation error:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// struct UnitStruct;
+///
+/// println!("{}", name_of!(fn UnitStruct));
+/// # }
+/// ```
+///
+/// Likewise, referring to a method that does not exist causes a compilation error:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// println!("{}", name_of!(fn nwe in Vec<u8>));
+/// # }
+/// ```
+///
+/// The same applies to misspelled module paths:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// println!("{}", name_of!(mod ::std::colections));
+/// # }
+/// ```
+///
+/// Or to misspelled associated types:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// println!("{}", name_of!(type Itme in Iterator));
+/// # }
+/// ```
+///
+/// Or to tuple struct fields that are out of range:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// struct Point(i32, i32);
+///
+/// println!("{}", name_of!(2 in Point));
+/// # }
+/// ```
+///
+/// The same applies to fields of tuple variants:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// enum Shape {
+///     Line(i32, i32),
+/// }
+///
+/// println!("{}", name_of!(2 in Shape::Line));
+/// # }
+/// ```
+///
+/// Or to fields not present in a struct variant:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// enum Color {
+///     Rgb { r: u8, g: u8, b: u8 },
+///     Hsl { h: u16, s: u8, l: u8 },
+/// }
+///
+/// println!("{}", name_of!(h in Color::Rgb));
+/// # }
+/// ```
+///
+/// Or to a binding that is not a constant when using the `const` keyword:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// let text = "Hello, World!";
+///
+/// println!("{}", name_of!(const text));
+/// # }
+/// ```
+///
+/// Or to a lifetime that has not been declared:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// fn lifetime_name<'a>(_: &'a str) -> &'static str {
+///     name_of!(lifetime 'b)
+/// }
+/// # }
+/// ```
 #[macro_export]
 macro_rules! name_of {
     // Covers Bindings
     ($n: ident) => {{
-        let _ = || {
-            let _ = &$n;
+        if false {
+            // Mutable statics may only be accessed within an unsafe block, even
+            // though the binding is never read since the branch is never taken.
+            #[allow(unused_unsafe)]
+            unsafe {
+                let _ = $n;
+            }
         };
-        stringify!($n)
+        $crate::__strip_raw!(stringify!($n))
+    }};
+
+    // Covers Generic Functions
+    ($n: ident :: < $($g: ty),+ >) => {{
+        if false {
+            let _ = $n::<$($g),+>;
+        }
+        $crate::__strip_raw!(stringify!($n))
+    }};
+
+    // Covers Bindings via Shared Reference
+    (ref $n: ident) => {{
+        if false {
+            let _ = &$n;
+        }
+        $crate::__strip_raw!(stringify!($n))
+    }};
+
+    // Covers Mutably Borrowed Bindings
+    (mut $n: ident) => {{
+        if false {
+            #[allow(unused_unsafe)]
+            unsafe {
+                let _ = $n;
+            }
+        }
+        $crate::__strip_raw!(stringify!($n))
+    }};
+
+    // Covers Bindings in snake_case
+    (snake $n: ident) => {{
+        let _ = $crate::name_of!($n);
+        $crate::__convert_case!($crate::__strip_raw!(stringify!($n)), b'_', false)
+    }};
+
+    // Covers Bindings in kebab-case
+    (kebab $n: ident) => {{
+        let _ = $crate::name_of!($n);
+        $crate::__convert_case!($crate::__strip_raw!(stringify!($n)), b'-', false)
     }};
 
+    // Covers Bindings in SCREAMING_SNAKE_CASE
+    (screaming $n: ident) => {{
+        let _ = $crate::name_of!($n);
+        $crate::__convert_case!($crate::__strip_raw!(stringify!($n)), b'_', true)
+    }};
+
+    // Covers Statics
+    (static $n: ident) => {{
+        if false {
+            // Taking the address of a mutable static requires an unsafe block on older compilers.
+            #[allow(unused_unsafe)]
+            unsafe {
+                let _ = $crate::__addr_of!($n);
+            }
+        }
+        $crate::__strip_raw!(stringify!($n))
+    }};
+
+    // Covers Lifetimes
+    (lifetime $l: lifetime) => {{
+        if false {
+            let _: &$l ();
+        }
+        stringify!($l)
+    }};
+
+    // Covers Macros
+    (macro $n: ident $(!)*) => {
+        $crate::__strip_raw!(stringify!($n))
+    };
+
+    // Covers Functions
+    (fn $n: ident) => {{
+        if false {
+            $crate::__assert_callable(&$n);
+        }
+        $crate::__strip_raw!(stringify!($n))
+    }};
+
+    // Covers Methods and Associated Functions
+    (fn $n: ident in $t: ty) => {{
+        if false {
+            let _ = <$t>::$n;
+        }
+        $crate::__strip_raw!(stringify!($n))
+    }};
+
+    // Covers Generic Methods and Associated Functions
+    (fn $n: ident :: < $($g: ty),+ > in $t: ty) => {{
+        if false {
+            let _ = <$t>::$n::<$($g),+>;
+        }
+        $crate::__strip_raw!(stringify!($n))
+    }};
+
+    // Covers Associated Types of Traits
+    (type $n: ident in $($tr: tt)+) => {{
+        if false {
+            $crate::__validate_bound!([type $n] $($tr)+);
+        }
+        $crate::__strip_raw!(stringify!($n))
+    }};
+
+    // Covers Impl Trait Types
+    (type impl $($b: tt)+) => {{
+        $crate::name_of_type!(impl $($b)+)
+    }};
+
+    // Covers Inferred Types
+    (type _) => {
+        $crate::name_of_type!(_)
+    };
+
+    // Covers Types by Absolute Paths
+    (type :: $($t: tt)+) => {
+        $crate::name_of_type!(:: $($t)+)
+    };
+
+    // Covers Types with Inferred Generic Arguments
+    (type $($p: ident)::+ < $($a: tt)+) => {
+        $crate::name_of_type!($($p)::+ < $($a)+)
+    };
+
     // Covers Types
     (type $t: ty) => {{
         $crate::name_of_type!($t)
     }};
 
-    // Covers Struct Fields
+    // Covers Generic Type Parameters
+    (type_param $t: ident) => {{
+        if false {
+            let _ = $crate::__PhantomData::<$t>;
+        }
+        $crate::__strip_raw!(stringify!($t))
+    }};
+
+    // Covers Const Generic Parameters
+    (const_param $n: ident) => {{
+        if false {
+            let _ = const { $n };
+        }
+        $crate::__strip_raw!(stringify!($n))
+    }};
+
+    // Covers Traits
+    (trait $($t: tt)+) => {{
+        if false {
+            $crate::__validate_bound!([dyn] $($t)+);
+        }
+        $crate::name_of!(@path $($t)+)
+    }};
+
+    // Covers Modules
+    (mod $($p: ident)::+) => {{
+        if false {
+            #[allow(unused_imports)]
+            use $($p)::+::*;
+        }
+        $crate::name_of!(@last $($p)::+)
+    }};
+
+    // Covers Absolute Module Paths
+    (mod :: $($p: ident)::+) => {{
+        if false {
+            #[allow(unused_imports)]
+            use :: $($p)::+::*;
+        }
+        $crate::name_of!(@last $($p)::+)
+    }};
+
+    // Covers Absolute Paths to Functions and Constants
+    (:: $($p: ident)::+) => {{
+        if false {
+            let _ = :: $($p)::+;
+        }
+        $crate::__strip_raw!($crate::name_of!(@last $($p)::+))
+    }};
+
+    // Covers Paths to Functions, Methods, and Constants
+    ($head: ident $(:: $tail: ident)+) => {{
+        if false {
+            let _ = $head$(::$tail)+;
+        }
+        $crate::__strip_raw!($crate::name_of!(@last $head$(::$tail)+))
+    }};
+
+    // Covers Mistaken Function Calls
+    ($p: ident $(:: $ps: ident)* ($($a: tt)*)) => {
+        compile_error!(concat!(
+            "name_of!() expects a function, not a call; remove the arguments and parentheses, e.g. `name_of!(",
+            stringify!($p),
+            $("::", stringify!($ps),)*
+            ")`"
+        ))
+    };
+
+    // Covers Mistaken Method Calls
+    ($b: ident . $m: ident ($($a: tt)*)) => {
+        compile_error!(concat!(
+            "name_of!() expects a method, not a call; refer to it by its type instead, e.g. `name_of!(fn ",
+            stringify!($m),
+            " in SomeType)`"
+        ))
+    };
+
+    // Covers Struct Variant Fields
+    ($n: ident in $e: ident $(:: < $($g: ty),+ >)* :: $v: ident) => {{
+        #[allow(unreachable_code, unreachable_patterns)]
+        if false {
+            match loop {} {
+                $e $(::<$($g),+>)* :: $v { $n: _, .. } => {}
+                _ => {}
+            }
+        }
+        $crate::__strip_raw!(stringify!($n))
+    }};
+
+    // Covers Struct and Union Fields
     ($n: ident in $t: ty) => {{
-        let _ = |f: $t| {
-            let _ = &f.$n;
-        };
-        stringify!($n)
+        // Fields are validated by their address only, since reading
+        // union fields or referencing packed fields is not allowed.
+        // Taking the address of a union field requires an unsafe block on older compilers.
+        // The binding is never initialized, since the branch is never taken.
+        #[allow(unreachable_code, unused_variables)]
+        if false {
+            let f: $t = loop {};
+            #[allow(unused_unsafe)]
+            unsafe {
+                let _ = $crate::__addr_of!(f.$n);
+            }
+        }
+        $crate::__strip_raw!(stringify!($n))
+    }};
+
+    // Covers Nested Struct Fields
+    ($n: ident $(. $m: ident)+ in $t: ty) => {{
+        #[allow(unreachable_code, unused_variables)]
+        if false {
+            let f: $t = loop {};
+            #[allow(unused_unsafe)]
+            unsafe {
+                let _ = $crate::__addr_of!(f.$n$(.$m)+);
+            }
+        }
+        $crate::__strip_raw!(concat!(stringify!($n), $(".", stringify!($m)),+))
+    }};
+
+    // Covers Tuple Variant Fields
+    ($i: tt in $e: ident $(:: < $($g: ty),+ >)* :: $v: ident) => {{
+        #[allow(unreachable_code, unreachable_patterns)]
+        if false {
+            match loop {} {
+                $e $(::<$($g),+>)* :: $v { $i: _, .. } => {}
+                _ => {}
+            }
+        }
+        stringify!($i)
+    }};
+
+    // Covers Tuple Struct Fields
+    ($i: tt in $t: ty) => {{
+        #[allow(unreachable_code, unused_variables)]
+        if false {
+            let f: $t = loop {};
+            #[allow(unused_unsafe)]
+            unsafe {
+                let _ = $crate::__addr_of!(f.$i);
+            }
+        }
+        stringify!($i)
+    }};
+
+    // Covers Fields of Bindings
+    ($b: ident . $f: tt) => {{
+        if false {
+            #[allow(unused_unsafe)]
+            unsafe {
+                let _ = $crate::__addr_of!($b.$f);
+            }
+        }
+        $crate::__strip_raw!(stringify!($f))
+    }};
+
+    // Covers Constants
+    (const $n: ident) => {{
+        const _: () = $crate::__forget($n);
+        $crate::__strip_raw!(stringify!($n))
+    }};
+
+    // Covers Associated Constants of Traits
+    (const $n: ident in trait $($tr: tt)+) => {{
+        if false {
+            $crate::__validate_bound!([const $n] $($tr)+);
+        }
+        $crate::__strip_raw!(stringify!($n))
     }};
 
     // Covers Struct Constants
     (const $n: ident in $t: ty) => {{
-        let _ = || {
+        if false {
             let _ = &<$t>::$n;
-        };
-        stringify!($n)
+        }
+        $crate::__strip_raw!(stringify!($n))
     }};
+
+    // Stringifies a path as written
+    (@path $p: path) => {
+        stringify!($p)
+    };
+
+    // Extracts the last segment of a path
+    (@last $n: ident) => {
+        $crate::__strip_raw!(stringify!($n))
+    };
+
+    (@last $head: ident :: $($tail: ident)::+) => {
+        $crate::name_of!(@last $($tail)::+)
+    };
 }
 
 /// Takes the name of a type as its sole parameter,
 /// e.g. `name_of_type!(SomeStruct)` or `name_of_type!(f64)`.
 ///
 /// It is an alternative to the `name_of!(type T)` macro, specifically for types.
+/// References, raw pointers, slices, arrays, tuples, and trait objects are supported as well,
+/// e.g. `name_of_type!(&[u8])`, `name_of_type!(*const u8)`, `name_of_type!((i32, String))`,
+/// or `name_of_type!(dyn Debug + Send)`, including boxed trait objects with several bounds,
+/// e.g. `name_of_type!(Box<dyn Error + Send + Sync>)`. The same applies to function pointer types,
+/// e.g. `name_of_type!(fn(i32) -> i32)` or `name_of_type!(extern "C" fn())`.
+/// Bounds of `impl Trait` types can be named as well, e.g. `name_of_type!(impl Iterator<Item = u8>)`.
+/// Associated types may be referred to by their fully qualified path,
+/// e.g. `name_of_type!(<Vec<u8> as IntoIterator>::IntoIter)`.
+/// Explicit lifetimes are preserved, e.g. `name_of_type!(&'static str)` returns `"&'static str"`,
+/// and must be declared in the current scope.
+/// The lengths of array types are returned as written, e.g. `name_of_type!([u8; LEN])`
+/// returns `"[u8; LEN]"`, and named lengths are validated like any other constant.
+///
+/// The whitespace of the returned string is normalized at compile time,
+/// e.g. `name_of_type!(Vec < HashMap < String,u32 > >)` returns `"Vec<HashMap<String, u32>>"`.
+///
+/// Within an `impl` block, `name_of_type!(Self)` returns the literal string `"Self"`,
+/// since the concrete type cannot be resolved at macro expansion time.
+/// Likewise, the inferred type `_` cannot be named, so `name_of_type!(_)` causes a compilation error
+/// explaining that the type must be specified explicitly.
+/// Generic arguments may be inferred, however, e.g. `name_of_type!(Vec<_>)` returns `"Vec<_>"`
+/// and `name_of_type!(HashMap<_, u32>)` returns `"HashMap<_, u32>"`. Inferred arguments are validated
+/// by substituting the unit type, which checks the type and its number of generic arguments.
+/// Only the outermost generic arguments may be inferred, so `Vec<Vec<_>>` is not supported.
+///
+/// Types referred to by an absolute path are returned without their path,
+/// e.g. `name_of_type!(::std::vec::Vec<u8>)` returns `"Vec<u8>"`, while the types of
+/// generic arguments are kept as written.
+///
+/// Type aliases are returned as written, e.g. `name_of_type!(Id)` returns `"Id"` after `type Id = u64;`.
+/// To get the name of the underlying type instead, use `name_of_type!(resolved Id)`, which returns `"u64"`.
+/// This form is provided by `core::any::type_name()` at runtime, so the returned name is fully qualified,
+/// e.g. `"alloc::string::String"`, cannot initialize constants, and its exact format is not guaranteed to be stable.
 ///
 /// # Examples
 ///
@@ -129,94 +680,4822 @@ macro_rules! name_of {
 ///
 /// # }
 /// ```
+///
+/// Referring to an array length that does not exist causes a compilation error:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// const LEN: usize = 16;
+///
+/// println!("{}", name_of_type!([u8; LNE]));
+/// # }
+/// ```
 #[macro_export]
 macro_rules! name_of_type {
-    // Covers Types
-    ($t: ty) => {{
-        let _ = || {
-            let _: $t;
-        };
-        stringify!($t)
+    // Covers Inferred Types
+    (_) => {
+        compile_error!("inferred types cannot be named, specify the type explicitly instead of `_`")
+    };
+
+    // Covers Absolute Paths with Inferred Generic Arguments
+    (:: $($p: ident)::+ < $($a: tt)+) => {{
+        if false {
+            $crate::name_of_type!(@find_infer [:: $($p)::+] [$($a)+] $($a)+);
+        }
+        $crate::__transform_str!(
+            $crate::__normalize_type!(stringify!(:: $($p)::+ < $($a)+)),
+            __unqualified_len,
+            __unqualify
+        )
     }};
-}
 
-#[cfg(test)]
-mod tests {
-    fn test_fn() {
-        //
-    }
+    // Covers Absolute Paths
+    (:: $($t: tt)+) => {{
+        if false {
+            let _: &:: $($t)+;
+        }
+        $crate::__transform_str!(
+            $crate::__normalize_type!(stringify!(:: $($t)+)),
+            __unqualified_len,
+            __unqualify
+        )
+    }};
 
-    struct TestStruct {
-        test_field: i32,
-    }
+    // Covers Resolved Type Aliases
+    (resolved $t: ty) => {
+        $crate::__type_name::<$t>()
+    };
 
-    impl TestStruct {
-        const TEST_CONST: i32 = 1;
-    }
+    // Covers Impl Trait Types
+    (impl $($b: tt)+) => {{
+        // Impl trait types cannot be used as the type of a local binding,
+        // so their bounds are validated individually instead.
+        if false {
+            $crate::__validate_bound!([dyn] $($b)+);
+        }
+        $crate::__normalize_type!(stringify!(impl $($b)+))
+    }};
 
-    struct TestGenericStruct<T> {
-        test_field: T,
-    }
+    // Covers Higher-Ranked Types
+    (for $($t: tt)+) => {{
+        if false {
+            let _: &for $($t)+;
+        }
+        $crate::__normalize_type!(stringify!(for $($t)+))
+    }};
 
-    struct TestGenericStructMultiType<T, U> {
-        test_field_t: T,
-        test_field_u: U,
-    }
+    // Covers Inferred Generic Arguments
+    ($($p: ident)::+ < $($a: tt)+) => {{
+        // Inferred arguments are replaced by the unit type for validation, since they cannot be inferred
+        // without a value, so that the type and its number of generic arguments are still checked.
+        #[allow(clippy::use_self)]
+        if false {
+            $crate::name_of_type!(@find_infer [$($p)::+] [$($a)+] $($a)+);
+        }
+        $crate::__normalize_type!(stringify!($($p)::+ < $($a)+))
+    }};
 
-    #[test]
-    fn name_of_binding() {
-        let test_variable = 123;
-        assert_eq!(name_of!(test_variable), "test_variable");
-    }
+    // Looks for inferred arguments, validating the type as written if there are none
+    (@find_infer [$($p: tt)*] [$($a: tt)*] _ $($r: tt)*) => {
+        $crate::name_of_type!(@infer [$($p)*] [] $($a)*)
+    };
+    (@find_infer [$($p: tt)*] [$($a: tt)*] $x: tt $($r: tt)*) => {
+        $crate::name_of_type!(@find_infer [$($p)*] [$($a)*] $($r)*)
+    };
+    (@find_infer [$($p: tt)*] [$($a: tt)*]) => {
+        let _: &$($p)* < $($a)*;
+    };
 
-    #[test]
-    fn name_of_fn() {
-        assert_eq!(name_of!(test_fn), "test_fn");
-    }
+    // Replaces inferred arguments
+    (@infer [$($p: tt)*] [$($d: tt)*] >) => {
+        let _: &$($p)* < $($d)* >;
+    };
+    (@infer [$($p: tt)*] [$($d: tt)*] _ , $($r: tt)+) => {
+        $crate::name_of_type!(@infer [$($p)*] [$($d)* (),] $($r)+)
+    };
+    (@infer [$($p: tt)*] [$($d: tt)*] _ >) => {
+        let _: &$($p)* < $($d)* () >;
+    };
+    (@infer [$($p: tt)*] [$($d: tt)*] $l: lifetime , $($r: tt)+) => {
+        $crate::name_of_type!(@infer [$($p)*] [$($d)* $l,] $($r)+)
+    };
+    (@infer [$($p: tt)*] [$($d: tt)*] $l: lifetime >) => {
+        let _: &$($p)* < $($d)* $l >;
+    };
+    (@infer [$($p: tt)*] [$($d: tt)*] $c: literal , $($r: tt)+) => {
+        $crate::name_of_type!(@infer [$($p)*] [$($d)* $c,] $($r)+)
+    };
+    (@infer [$($p: tt)*] [$($d: tt)*] $c: literal >) => {
+        let _: &$($p)* < $($d)* $c >;
+    };
+    (@infer [$($p: tt)*] [$($d: tt)*] $c: block , $($r: tt)+) => {
+        $crate::name_of_type!(@infer [$($p)*] [$($d)* $c,] $($r)+)
+    };
+    (@infer [$($p: tt)*] [$($d: tt)*] $c: block >) => {
+        let _: &$($p)* < $($d)* $c >;
+    };
+    (@infer [$($p: tt)*] [$($d: tt)*] $a: ty , $($r: tt)+) => {
+        $crate::name_of_type!(@infer [$($p)*] [$($d)* $a,] $($r)+)
+    };
+    (@infer [$($p: tt)*] [$($d: tt)*] $a: ty >) => {
+        let _: &$($p)* < $($d)* $a >;
+    };
 
-    #[test]
-    fn name_of_type() {
-        assert_eq!(name_of!(type i32), "i32");
-        assert_eq!(name_of_type!(i32), "i32");
-    }
+    // Covers Types
+    ($t: ty) => {{
+        // The type is referred to by reference in order to support unsized types such as trait objects.
+        #[allow(clippy::use_self)]
+        if false {
+            let _: &$t;
+        }
+        $crate::__normalize_type!(stringify!($t))
+    }};
+}
 
-    #[test]
-    fn name_of_struct() {
-        assert_eq!(name_of!(type TestStruct), "TestStruct");
-        assert_eq!(name_of_type!(TestStruct), "TestStruct");
-    }
+/// Takes a type as an argument and returns its name without generic arguments,
+/// e.g. `short_name_of_type!(HashMap<String, u32>)` returns `"HashMap"`.
+///
+/// The type is validated like in `name_of_type!()`, and the name is shortened at compile time,
+/// so the result can initialize constants. Non-generic types are returned unchanged.
+/// The path of the type is kept, e.g. `short_name_of_type!(std::vec::Vec<u8>)` returns `"std::vec::Vec"`,
+/// unless the type is referred to by an absolute path. Only types that are paths are shortened,
+/// so references, slices, and qualified paths such as `<Vec<u8> as IntoIterator>::IntoIter`
+/// are returned as by `name_of_type!()`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # use std::collections::HashMap;
+/// # fn main() {
+/// struct TestGenericStruct<T> {
+///     test_field: T,
+/// }
+///
+/// assert_eq!(short_name_of_type!(HashMap<String, u32>), "HashMap");
+/// assert_eq!(short_name_of_type!(TestGenericStruct<_>), "TestGenericStruct");
+/// assert_eq!(short_name_of_type!(i32), "i32");
+/// # }
+/// ```
+#[macro_export]
+macro_rules! short_name_of_type {
+    ($($t: tt)+) => {
+        $crate::__transform_str!(
+            $crate::name_of_type!($($t)+),
+            __shortened_type_len,
+            __shorten_type
+        )
+    };
+}
 
-    #[test]
-    fn name_of_generic_struct() {
-        assert_eq!(
-            name_of!(type TestGenericStruct<i32>),
-            "TestGenericStruct<i32>"
-        );
+/// Takes a binding, function, or type as an argument and returns its name
+/// qualified with the path of the current module, e.g. `"my_crate::services::UserService"`.
+///
+/// The syntax follows `name_of!()`: bindings and functions require no annotation,
+/// while types require the keyword `type`.
+///
+/// Note that the module path is determined by `module_path!()` at the call site.
+/// For items imported from other modules or crates, the result reflects the module
+/// in which the macro is used, not the module in which the item has been defined.
+/// Types referred to by an absolute path are the exception, since they are returned
+/// as their path, e.g. `full_name_of!(type ::std::vec::Vec<u8>)` returns `"std::vec::Vec<u8>"`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// struct TestStruct {
+///     test_field: i32,
+/// }
+///
+/// let text = "Hello, World!";
+///
+/// println!("Binding is called `{}`.", full_name_of!(text));
+/// println!("Struct is called `{}`.", full_name_of!(type TestStruct));
+///
+/// # }
+/// ```
+#[macro_export]
+macro_rules! full_name_of {
+    // Covers Bindings
+    ($n: ident) => {{
+        let _ = $crate::name_of!($n);
+        $crate::__strip_raw!(concat!(module_path!(), "::", stringify!($n)))
+    }};
 
-        assert_eq!(
-            name_of_type!(TestGenericStruct<i32>),
-            "TestGenericStruct<i32>"
+    // Covers Types by Absolute Paths
+    (type :: $($t: tt)+) => {{
+        let _ = $crate::name_of_type!(:: $($t)+);
+        $crate::__normalize_type!(stringify!($($t)+))
+    }};
+
+    // Covers Types
+    (type $t: ty) => {{
+        let _ = $crate::name_of_type!($t);
+        concat!(module_path!(), "::", stringify!($t))
+    }};
+}
+
+/// Returns the path of the current module as provided by `module_path!()`,
+/// e.g. `"my_crate::services"`. When given the same arguments as `full_name_of!()`,
+/// the validated name is appended to the module path, e.g. `module_path_of!(greet)`
+/// returns `"my_crate::services::greet"`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// fn greet() {}
+///
+/// println!("Module is called `{}`.", module_path_of!());
+/// println!("Function is called `{}`.", module_path_of!(greet));
+///
+/// # }
+/// ```
+#[macro_export]
+macro_rules! module_path_of {
+    // Covers the Current Module
+    () => {
+        module_path!()
+    };
+
+    // Covers all forms supported by full_name_of!
+    ($($t: tt)+) => {
+        $crate::full_name_of!($($t)+)
+    };
+}
+
+/// Takes a comma-separated list of bindings or functions and returns an array
+/// of their names, e.g. `names_of!(a, b, c)` returns `["a", "b", "c"]`.
+///
+/// Each identifier is validated in the same way as `name_of!()`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// let width = 1920;
+/// let height = 1080;
+///
+/// assert_eq!(names_of!(width, height), ["width", "height"]);
+///
+/// # }
+/// ```
+///
+/// A misspelled identifier in the list causes a compilation error:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// let width = 1920;
+/// let height = 1080;
+///
+/// println!("{:?}", names_of!(width, hieght));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! names_of {
+    // Covers Bindings
+    ($($n: ident),+ $(,)*) => {
+        [$($crate::name_of!($n)),+]
+    };
+}
+
+/// Takes the name of an enum followed by a list of all its variants in braces,
+/// e.g. `variant_count_of!(Color { Red, Green, Blue })`, and returns the number of variants as a `usize`.
+///
+/// Since the number of variants cannot be determined on stable Rust without listing them,
+/// the listed variants are validated against the enum definition. The list must be complete,
+/// i.e. omitting a variant, specifying one that does not exist, or listing one more than once
+/// causes a compilation error.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// enum Color {
+///     Red,
+///     Green,
+///     Blue,
+///     Rgb(u8, u8, u8),
+///     Hsl { h: u16, s: u8, l: u8 },
+/// }
+///
+/// assert_eq!(variant_count_of!(Color { Red, Green, Blue, Rgb, Hsl }), 5);
+///
+/// # }
+/// ```
+///
+/// An incomplete list of variants causes a compilation error:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// enum Color {
+///     Red,
+///     Green,
+///     Blue,
+/// }
+///
+/// println!("{}", variant_count_of!(Color { Red, Green }));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! variant_count_of {
+    // Covers Enums
+    ($e: ident { $($v: ident),+ $(,)* }) => {
+        $crate::variants_of!($e { $($v),+ }).len()
+    };
+}
+
+/// Takes the name of an enum followed by a list of all its variants in braces,
+/// e.g. `variants_of!(Color { Red, Green, Blue })`, and returns an array of the
+/// variant names, e.g. `["Red", "Green", "Blue"]`.
+///
+/// The listed variants are validated against the enum definition in the same way as `variant_count_of!()`.
+/// Enums marked `#[non_exhaustive]` are only supported within the crate that defines them,
+/// since their variants cannot be matched exhaustively elsewhere.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// enum Color {
+///     Red,
+///     Green,
+///     Blue,
+///     Rgb(u8, u8, u8),
+///     Hsl { h: u16, s: u8, l: u8 },
+/// }
+///
+/// assert_eq!(
+///     variants_of!(Color { Red, Green, Blue, Rgb, Hsl }),
+///     ["Red", "Green", "Blue", "Rgb", "Hsl"]
+/// );
+///
+/// # }
+/// ```
+///
+/// Specifying a variant that does not exist causes a compilation error:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// enum Color {
+///     Red,
+///     Green,
+///     Blue,
+/// }
+///
+/// println!("{:?}", variants_of!(Color { Red, Green, Blue, Purple }));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! variants_of {
+    // Covers Enums
+    ($e: ident { $($v: ident),+ $(,)* }) => {{
+        const _: () = assert!(
+            $crate::__all_unique(&[$($crate::__strip_raw!(stringify!($v))),+]),
+            "variants are listed more than once"
+        );
+        #[allow(unreachable_code)]
+        if false {
+            match loop {} {
+                $($e::$v { .. } => {})+
+            }
+        }
+        [$(stringify!($v)),+]
+    }};
+}
+
+/// Takes the name of an enum followed by a list of all its variants and their shapes in braces,
+/// e.g. `enum_variants_with_values_of!(Color { Red, Rgb(u8, u8, u8), Hsl { h, s, l } })`,
+/// and returns an array describing each variant, e.g. `["Red", "Rgb(u8, u8, u8)", "Hsl { h, s, l }"]`.
+///
+/// This is a richer form of `variants_of!()` intended for documenting the shape of enums.
+/// The list is validated in the same way, and in addition, the types of tuple variants as well as
+/// the fields of struct variants must match the enum definition exactly and completely.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// enum Color {
+///     Red,
+///     Rgb(u8, u8, u8),
+///     Hsl { h: u16, s: u8, l: u8 },
+/// }
+///
+/// assert_eq!(
+///     enum_variants_with_values_of!(Color { Red, Rgb(u8, u8, u8), Hsl { h, s, l } }),
+///     ["Red", "Rgb(u8, u8, u8)", "Hsl { h, s, l }"]
+/// );
+///
+/// # }
+/// ```
+#[macro_export]
+macro_rules! enum_variants_with_values_of {
+    // Covers Enums
+    ($e: ident { $($v: tt)+ }) => {
+        $crate::enum_variants_with_values_of!(@munch $e [] [] [] $($v)+)
+    };
+
+    (@munch $e: ident [$($p: tt)*] [$($c: tt)*] [$($n: tt)*]) => {{
+        #[allow(unreachable_code)]
+        if false {
+            $($c)*
+            match loop {} {
+                $($p)*
+            }
+        }
+        [$($n)*]
+    }};
+
+    (@munch $e: ident [$($p: tt)*] [$($c: tt)*] [$($n: tt)*] , $($rest: tt)*) => {
+        $crate::enum_variants_with_values_of!(@munch $e [$($p)*] [$($c)*] [$($n)*] $($rest)*)
+    };
+
+    // Covers Tuple Variants
+    (@munch $e: ident [$($p: tt)*] [$($c: tt)*] [$($n: tt)*] $v: ident ($($t: ty),+ $(,)*) $($rest: tt)*) => {
+        $crate::enum_variants_with_values_of!(
+            @munch $e
+            [$($p)* $e::$v(..) => {}]
+            [$($c)* let _: fn($($t),+) -> $e = $e::$v;]
+            [$($n)* $crate::__normalize_type!(concat!(stringify!($v), "(", stringify!($($t),+), ")")),]
+            $($rest)*
+        )
+    };
+
+    // Covers Struct Variants
+    (@munch $e: ident [$($p: tt)*] [$($c: tt)*] [$($n: tt)*] $v: ident { $($f: ident),+ $(,)* } $($rest: tt)*) => {
+        $crate::enum_variants_with_values_of!(
+            @munch $e
+            [$($p)* $e::$v { $($f: _),+ } => {}]
+            [$($c)*]
+            [$($n)* $crate::__strip_raw!(concat!(stringify!($v), " { ", stringify!($($f),+), " }")),]
+            $($rest)*
+        )
+    };
+
+    // Covers Unit Variants
+    (@munch $e: ident [$($p: tt)*] [$($c: tt)*] [$($n: tt)*] $v: ident $($rest: tt)*) => {
+        $crate::enum_variants_with_values_of!(
+            @munch $e
+            [$($p)* $e::$v => {}]
+            [$($c)*]
+            [$($n)* $crate::__strip_raw!(stringify!($v)),]
+            $($rest)*
+        )
+    };
+}
+
+/// Takes the name of a variant followed by `in`, the name of its enum, and a list of all variants
+/// in braces, e.g. `variant_index_of!(Blue in Color { Red, Green, Blue })`, and returns the index
+/// of the variant within the list as a `usize`, e.g. `2`.
+///
+/// The listed variants are validated against the enum definition in the same way as `variant_count_of!()`,
+/// and the variant must be part of the list. The index refers to the order of the list, not to the order
+/// in which the variants are declared, since the latter cannot be determined on stable Rust,
+/// e.g. `variant_index_of!(Blue in Color { Blue, Red, Green })` returns `0`. Listing the variants in
+/// declaration order is therefore up to the caller, as is keeping the list in sync when variants are reordered.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// enum Color {
+///     Red,
+///     Green,
+///     Blue,
+/// }
+///
+/// assert_eq!(variant_index_of!(Red in Color { Red, Green, Blue }), 0);
+/// assert_eq!(variant_index_of!(Blue in Color { Red, Green, Blue }), 2);
+///
+/// # }
+/// ```
+#[macro_export]
+macro_rules! variant_index_of {
+    // Covers Enums
+    ($v: ident in $e: ident { $($vs: ident),+ $(,)* }) => {{
+        let _ = $crate::variants_of!($e { $($vs),+ });
+        const INDEX: usize = $crate::__index_of(stringify!($v), &[$(stringify!($vs)),+]);
+        INDEX
+    }};
+}
+
+/// Takes an enum value followed by `in`, the name of its enum, and a list of all variants in braces,
+/// e.g. `discriminant_name_of!(color in Color { Red, Green, Blue })`, and returns the name of the
+/// variant of the value at runtime, e.g. `"Green"`.
+///
+/// Since enums cannot be reflected upon, the variants must be listed. The list is validated in the
+/// same way as `variants_of!()`, so it must be complete. The value may be an enum or a reference
+/// to one and is borrowed, not consumed. Expressions other than bindings must be enclosed
+/// in parentheses, e.g. `discriminant_name_of!((shapes[0]) in Shape { Circle, Line, Empty })`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// enum Shape {
+///     Circle { radius: u32 },
+///     Line(u32, u32),
+///     Empty,
+/// }
+///
+/// let shapes = [Shape::Line(1, 2), Shape::Circle { radius: 3 }];
+///
+/// for shape in shapes.iter() {
+///     println!("Shape is a `{}`.", discriminant_name_of!(shape in Shape { Circle, Line, Empty }));
+/// }
+///
+/// # }
+/// ```
+#[macro_export]
+macro_rules! discriminant_name_of {
+    // Covers Enums
+    ($x: tt in $e: ident { $($v: ident),+ $(,)* }) => {
+        match &$x {
+            $($e::$v { .. } => $crate::__strip_raw!(stringify!($v)),)+
+        }
+    };
+}
+
+/// Takes the name of a struct followed by a list of all its fields in braces,
+/// e.g. `field_names_of!(Point { x, y })`, and returns an array of the field names, e.g. `["x", "y"]`.
+///
+/// The listed fields are validated against the struct definition. The list must be complete,
+/// i.e. omitting a field or specifying one that does not exist causes a compilation error.
+/// Generic structs require their type arguments, e.g. `field_names_of!(Wrapper<u8> { value })`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// assert_eq!(field_names_of!(Point { x, y }), ["x", "y"]);
+///
+/// # }
+/// ```
+///
+/// Specifying a field that does not exist causes a compilation error:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// println!("{:?}", field_names_of!(Point { x, y, z }));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! field_names_of {
+    // Covers Structs
+    ($s: ident $(< $($g: ty),+ >)* { $($n: ident),+ $(,)* }) => {{
+        #[allow(unreachable_code)]
+        if false {
+            let $s { $($n: _),+ }: $s $(<$($g),+>)* = loop {};
+        }
+        [$($crate::__strip_raw!(stringify!($n))),+]
+    }};
+}
+
+/// Takes a type implementing `FieldNames` and returns a slice of all its field names,
+/// e.g. `all_field_names_of!(Point)` returns `["x", "y"]`.
+///
+/// Unlike `field_names_of!()`, the fields do not have to be listed. Instead, the type
+/// has to implement `FieldNames`, which can be derived using `#[derive(FieldNames)]`
+/// if the `derive` feature is enabled.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// use nameof::FieldNames;
+///
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// impl FieldNames for Point {
+///     const FIELD_NAMES: &'static [&'static str] = &field_names_of!(Point { x, y });
+/// }
+///
+/// assert_eq!(all_field_names_of!(Point), ["x", "y"]);
+///
+/// # }
+/// ```
+#[macro_export]
+macro_rules! all_field_names_of {
+    // Covers Types
+    ($t: ty) => {
+        <$t as $crate::FieldNames>::FIELD_NAMES
+    };
+}
+
+/// Takes the name of a field followed by `in` and a type implementing `SerdeFieldNames`,
+/// e.g. `serde_name_of!(x in Point)`, and returns the name under which the field is serialized.
+///
+/// If the field has been renamed using `#[serde(rename = "...")]` or `#[serde(rename_all = "...")]`
+/// of the struct, the renamed string is returned, otherwise the name of the field itself. The field is validated in the same way as in `name_of!()`.
+/// This macro requires the `serde` feature, which allows deriving `SerdeFieldNames`
+/// using `#[derive(SerdeFieldNames)]`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// use nameof::SerdeFieldNames;
+///
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// impl SerdeFieldNames for Point {
+///     const SERDE_FIELD_NAMES: &'static [(&'static str, &'static str)] = &[("x", "pos_x"), ("y", "y")];
+/// }
+///
+/// assert_eq!(serde_name_of!(x in Point), "pos_x");
+/// assert_eq!(serde_name_of!(y in Point), "y");
+///
+/// # }
+/// ```
+#[cfg(feature = "serde")]
+#[macro_export]
+macro_rules! serde_name_of {
+    // Covers Struct Fields
+    ($n: ident in $t: ty) => {
+        $crate::__serde_name_of(
+            $crate::name_of!($n in $t),
+            <$t as $crate::SerdeFieldNames>::SERDE_FIELD_NAMES,
+        )
+    };
+}
+
+/// Takes a path to an item, e.g. a module, type, or function, and returns an array of its segments,
+/// e.g. `path_segments_of!(std::collections::HashMap)` returns `["std", "collections", "HashMap"]`.
+///
+/// The path is validated by importing it, i.e. it is resolved in the same way as a `use` declaration
+/// in the current module. Paths may start with `crate`, `self`, or `super`.
+/// The leading `::` of absolute paths is not included in the result.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// assert_eq!(
+///     path_segments_of!(std::collections::HashMap),
+///     ["std", "collections", "HashMap"]
+/// );
+///
+/// # }
+/// ```
+#[macro_export]
+macro_rules! path_segments_of {
+    // Covers Paths
+    ($($p: ident)::+) => {{
+        if false {
+            #[allow(unused_imports)]
+            use $($p)::+ as _;
+        }
+        [$($crate::__strip_raw!(stringify!($p))),+]
+    }};
+
+    // Covers Absolute Paths
+    (:: $($p: ident)::+) => {{
+        if false {
+            #[allow(unused_imports)]
+            use :: $($p)::+ as _;
+        }
+        [$($crate::__strip_raw!(stringify!($p))),+]
+    }};
+}
+
+/// Takes the same arguments as `name_of!()` and returns the length of the name in bytes,
+/// e.g. `name_len_of!(some_function)` returns `13`.
+///
+/// The result can be evaluated at compile time and thus be used as the length of an array,
+/// e.g. `[0u8; name_len_of!(some_function)]`. Note that local bindings cannot be referred to
+/// in constant expressions, which is why only items such as functions, types, constants, or
+/// fields can be used in this position.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// struct TestStruct {
+///     test_field: i32,
+/// }
+///
+/// let buffer = [0u8; name_len_of!(test_field in TestStruct)];
+/// assert_eq!(buffer.len(), 10);
+///
+/// # }
+/// ```
+#[macro_export]
+macro_rules! name_len_of {
+    // Covers all forms supported by name_of!
+    ($($t: tt)+) => {
+        $crate::name_of!($($t)+).len()
+    };
+}
+
+/// Takes the same arguments as `name_of!()` and returns the name as a byte slice of type `&'static [u8]`,
+/// e.g. `bytes_of_name!(some_function)` returns `b"some_function"`.
+///
+/// Like `name_len_of!()`, the result can be evaluated at compile time if the macro refers to an item.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// struct TestStruct {
+///     test_field: i32,
+/// }
+///
+/// const FIELD: &[u8] = bytes_of_name!(test_field in TestStruct);
+/// assert_eq!(FIELD, b"test_field");
+///
+/// # }
+/// ```
+#[macro_export]
+macro_rules! bytes_of_name {
+    // Covers all forms supported by name_of!
+    ($($t: tt)+) => {
+        $crate::name_of!($($t)+).as_bytes()
+    };
+}
+
+/// Takes the same arguments as `name_of!()` and returns the 64-bit FNV-1a hash of the name as a `u64`.
+///
+/// Since the hash is computed by a `const fn`, it can be evaluated at compile time if the macro
+/// refers to an item, e.g. in order to use the hash as a pattern in `match` arms.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// struct Config {
+///     verbose: bool,
+///     threads: usize,
+/// }
+///
+/// const VERBOSE: u64 = name_hash_of!(verbose in Config);
+/// const THREADS: u64 = name_hash_of!(threads in Config);
+///
+/// let key = "threads";
+/// let hash = key.bytes().fold(0xcbf29ce484222325, |hash, b| (hash ^ b as u64).wrapping_mul(0x100000001b3));
+///
+/// match hash {
+///     VERBOSE => println!("Setting verbosity."),
+///     THREADS => println!("Setting the number of threads."),
+///     _ => println!("Unknown setting."),
+/// }
+///
+/// # }
+/// ```
+#[macro_export]
+macro_rules! name_hash_of {
+    // Covers all forms supported by name_of!
+    ($($t: tt)+) => {
+        $crate::__fnv1a_hash($crate::name_of!($($t)+))
+    };
+}
+
+/// Takes a string literal or constant followed by a binding or a field, e.g. `prefixed_name_of!("app.", some_field in Config)`,
+/// and returns the name prefixed by the literal, e.g. `"app.some_field"`.
+///
+/// The binding or field is validated in the same way as in `name_of!()`. The result is concatenated
+/// at compile time, so no allocation is needed, and it can initialize constants if the macro refers to a field.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// struct Config {
+///     port: u16,
+/// }
+///
+/// let timeout = 30;
+///
+/// const PORT_KEY: &str = prefixed_name_of!("app.", port in Config);
+///
+/// assert_eq!(PORT_KEY, "app.port");
+/// assert_eq!(prefixed_name_of!("app.", timeout), "app.timeout");
+///
+/// # }
+/// ```
+#[macro_export]
+macro_rules! prefixed_name_of {
+    // Covers Bindings
+    ($p: expr, $n: ident) => {{
+        let _ = $crate::name_of!($n);
+        $crate::__concat_str!($p, $crate::__strip_raw!(stringify!($n)))
+    }};
+
+    // Covers Fields
+    ($p: expr, $n: tt in $t: ty) => {{
+        let _ = $crate::name_of!($n in $t);
+        $crate::__concat_str!($p, $crate::__strip_raw!(stringify!($n)))
+    }};
+}
+
+/// Takes a prefix, a binding or a field, and a suffix, e.g. `decorate_name_of!("set_", some_field in Config, "_key")`,
+/// and returns the name enclosed by the prefix and the suffix, e.g. `"set_some_field_key"`.
+///
+/// This extends `prefixed_name_of!()`, which the macro behaves like in every other respect.
+/// Either affix may be empty, e.g. `decorate_name_of!("", some_field in Config, "_changed")`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// struct Config {
+///     port: u16,
+/// }
+///
+/// const GETTER: &str = decorate_name_of!("get_", port in Config, "");
+/// const SETTER: &str = decorate_name_of!("set_", port in Config, "_value");
+///
+/// assert_eq!(GETTER, "get_port");
+/// assert_eq!(SETTER, "set_port_value");
+///
+/// # }
+/// ```
+#[macro_export]
+macro_rules! decorate_name_of {
+    // Covers Bindings
+    ($p: expr, $n: ident, $s: expr) => {{
+        let _ = $crate::name_of!($n);
+        $crate::decorate_name_of!(@decorate $p, $n, $s)
+    }};
+
+    // Covers Fields
+    ($p: expr, $n: tt in $t: ty, $s: expr) => {{
+        let _ = $crate::name_of!($n in $t);
+        $crate::decorate_name_of!(@decorate $p, $n, $s)
+    }};
+
+    (@decorate $p: expr, $n: tt, $s: expr) => {
+        $crate::__concat_str!(
+            $crate::__concat_str!($p, $crate::__strip_raw!(stringify!($n))),
+            $s
+        )
+    };
+}
+
+/// Takes the same arguments as `name_of!()` and returns the name as a NUL-terminated `&'static CStr`,
+/// e.g. `cstr_name_of!(some_function)` returns `c"some_function"`, which is useful at FFI boundaries.
+///
+/// The terminating NUL is appended at compile time, so no allocation is needed. Like `bytes_of_name!()`,
+/// the result can be evaluated at compile time if the macro refers to an item or a field.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// use std::ffi::CStr;
+///
+/// struct Config {
+///     port: u16,
+/// }
+///
+/// const PORT: &CStr = cstr_name_of!(port in Config);
+///
+/// assert_eq!(PORT.to_bytes_with_nul(), b"port\0");
+///
+/// # }
+/// ```
+#[macro_export]
+macro_rules! cstr_name_of {
+    // Covers Bindings
+    ($n: ident) => {
+        $crate::__cstr_from_str($crate::decorate_name_of!("", $n, "\0"))
+    };
+
+    // Covers Fields
+    ($n: tt in $t: ty) => {
+        $crate::__cstr_from_str($crate::decorate_name_of!("", $n in $t, "\0"))
+    };
+
+    // Covers all other forms supported by name_of!
+    ($($t: tt)+) => {
+        $crate::__cstr_from_str($crate::__concat_str!($crate::name_of!($($t)+), "\0"))
+    };
+}
+
+/// Takes the name of a field followed by `in` and a type, e.g. `const_field_name!(some_field in SomeType)`,
+/// and returns the name of the field, which is always evaluated at compile time.
+///
+/// The field forms of `name_of!()` can already initialize constants. This macro additionally guarantees
+/// that the name is computed by a constant, which makes the intent explicit when defining field name
+/// constants, e.g. `const X_FIELD: &str = const_field_name!(x in Point);`. Nested fields and fields of
+/// tuple structs are supported as well. Since constants cannot refer to generic parameters of an
+/// enclosing item, the type must be concrete.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// struct Line(Point, Point);
+///
+/// const X_FIELD: &str = const_field_name!(x in Point);
+/// const END_X_FIELD: &str = const_field_name!(1.x in Line);
+///
+/// assert_eq!(X_FIELD, "x");
+/// assert_eq!(END_X_FIELD, "1.x");
+///
+/// # }
+/// ```
+#[macro_export]
+macro_rules! const_field_name {
+    // Covers Struct and Tuple Struct Fields
+    ($n: tt in $t: ty) => {{
+        const __NAMEOF_NAME: &str = $crate::name_of!($n in $t);
+        __NAMEOF_NAME
+    }};
+
+    // Covers Nested Fields
+    ($n: tt $(. $m: tt)+ in $t: ty) => {{
+        #[allow(unreachable_code, unused_variables)]
+        const __NAMEOF_NAME: &str = {
+            if false {
+                let f: $t = loop {};
+                #[allow(unused_unsafe)]
+                unsafe {
+                    let _ = $crate::__addr_of!(f.$n$(.$m)+);
+                }
+            }
+            $crate::__strip_raw!(concat!(stringify!($n), $(".", stringify!($m)),+))
+        };
+        __NAMEOF_NAME
+    }};
+}
+
+/// Takes the same arguments as `name_of!()` and returns the name wrapped in the newtype `Name`,
+/// e.g. `name_token_of!(some_state)` returns `Name::new("some_state")`.
+///
+/// Unlike a plain `&'static str`, a `Name` documents that the string is the validated name of an item,
+/// which is useful for state machines and similar code that passes names around. Like `bytes_of_name!()`,
+/// the result can be evaluated at compile time if the macro refers to an item.
+/// A `Name` implements `Display`, `AsRef<str>`, and `Deref<Target = str>`, and compares equal to strings.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// use nameof::Name;
+///
+/// fn idle() {}
+/// fn running() {}
+///
+/// const IDLE: Name = name_token_of!(idle);
+///
+/// let state = name_token_of!(running);
+///
+/// assert_ne!(state, IDLE);
+/// assert_eq!(state, "running");
+/// assert_eq!(format!("State: {}", state), "State: running");
+///
+/// # }
+/// ```
+#[macro_export]
+macro_rules! name_token_of {
+    // Covers all forms supported by name_of!
+    ($($t: tt)+) => {
+        $crate::Name::new($crate::name_of!($($t)+))
+    };
+}
+
+/// Takes a string and the same arguments as `name_of!()` and returns whether the string
+/// equals the name, e.g. `matches_name!(key, some_field in SomeType)`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// struct Config {
+///     verbose: bool,
+///     threads: usize,
+/// }
+///
+/// let mut config = Config { verbose: false, threads: 1 };
+///
+/// for (key, value) in [("verbose", "true"), ("threads", "4")] {
+///     if matches_name!(key, verbose in Config) {
+///         config.verbose = value.parse().unwrap();
+///     } else if matches_name!(key, threads in Config) {
+///         config.threads = value.parse().unwrap();
+///     }
+/// }
+///
+/// assert!(config.verbose);
+/// assert_eq!(config.threads, 4);
+///
+/// # }
+/// ```
+///
+/// Referring to an identifier that does not exist causes a compilation error:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// let verbose = true;
+///
+/// println!("{}", matches_name!("verbose", verbos));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! matches_name {
+    // Covers all forms supported by name_of!
+    ($s: expr, $($t: tt)+) => {
+        $s == $crate::name_of!($($t)+)
+    };
+}
+
+/// Takes the same arguments as `name_of!()` followed by a string literal and causes a compilation
+/// error if the name does not equal the literal, e.g. `const_assert_name!(some_field in SomeType, "some_field")`.
+///
+/// This guards against renames that would silently break contracts such as serialized field names.
+/// Since the comparison is performed in a constant, only items such as functions, types, constants,
+/// or fields can be referred to, but not local bindings. The macro may be used wherever items
+/// are allowed, including at module level.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// struct Config {
+///     verbose: bool,
+/// }
+///
+/// const_assert_name!(verbose in Config, "verbose");
+/// const_assert_name!(type Config, "Config");
+///
+/// # }
+/// ```
+///
+/// A name that does not equal the literal causes a compilation error:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// struct Config {
+///     verbose: bool,
+/// }
+///
+/// const_assert_name!(verbose in Config, "verbosity");
+/// # }
+/// ```
+#[macro_export]
+macro_rules! const_assert_name {
+    // Splits the arguments at the last comma
+    (@split [$($n: tt)+], $expected: literal) => {
+        const _: () = assert!(
+            $crate::__str_eq($crate::name_of!($($n)+), $expected),
+            "name does not equal the expected literal"
+        );
+    };
+
+    (@split [$($n: tt)*] $head: tt $($tail: tt)*) => {
+        $crate::const_assert_name!(@split [$($n)* $head] $($tail)*);
+    };
+
+    // Covers all forms supported by name_of!
+    ($($t: tt)+) => {
+        $crate::const_assert_name!(@split [] $($t)+);
+    };
+}
+
+/// Takes a comma-separated list of items and causes a compilation error if any two of their names
+/// are equal, e.g. `assert_unique_names!(start, stop, pause)`.
+///
+/// This guards dispatch tables and similar lookups against duplicate keys, e.g. when the same
+/// identifier is forwarded once as a raw identifier such as `r#type` and once as `type`.
+/// The names are validated in the same way as `names_of!()`. Like `const_assert_name!()`,
+/// the check is performed in a constant, so only items but not local bindings can be referred to.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// fn start() {}
+/// fn stop() {}
+///
+/// assert_unique_names!(start, stop);
+///
+/// # }
+/// ```
+///
+/// Duplicated names cause a compilation error:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// fn start() {}
+/// fn stop() {}
+///
+/// assert_unique_names!(start, stop, start);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_unique_names {
+    // Covers Items
+    ($($n: ident),+ $(,)*) => {
+        const _: () = assert!(
+            $crate::__all_unique(&$crate::names_of!($($n),+)),
+            "names are not unique"
+        );
+    };
+}
+
+/// Takes a path to an enum variant and returns the name of the enum,
+/// e.g. `enum_name_of!(Color::Red)` returns `"Color"`.
+///
+/// The variant is validated against the enum definition, regardless of whether it is a unit,
+/// tuple, or struct variant. Optionally, tuple and struct variants may be denoted as
+/// `enum_name_of!(Color::Rgb(..))` and `enum_name_of!(Color::Hsl { .. })` respectively.
+/// Generic enums require their type arguments, e.g. `enum_name_of!(Option::<u8>::Some)`.
+/// The enum may be qualified by a module path, e.g. `enum_name_of!(crate::Color::Red)`,
+/// in which case only the name of the enum itself is returned.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// enum Color {
+///     Red,
+///     Rgb(u8, u8, u8),
+///     Hsl { h: u16, s: u8, l: u8 },
+/// }
+///
+/// assert_eq!(enum_name_of!(Color::Red), "Color");
+/// assert_eq!(enum_name_of!(Color::Rgb(..)), "Color");
+/// assert_eq!(enum_name_of!(Color::Hsl { .. }), "Color");
+///
+/// # }
+/// ```
+#[macro_export]
+macro_rules! enum_name_of {
+    // Covers Variants
+    ($($p: ident)::+) => {{
+        #[allow(unreachable_code, unreachable_patterns)]
+        if false {
+            match loop {} {
+                $($p)::+ { .. } => {}
+                _ => {}
+            }
+        }
+        $crate::__strip_raw!($crate::enum_name_of!(@enum $($p)::+))
+    }};
+
+    // Covers Variants of Generic Enums
+    ($($p: ident)::+ :: < $($g: ty),+ > :: $v: ident) => {{
+        #[allow(unreachable_code, unreachable_patterns)]
+        if false {
+            match loop {} {
+                $($p)::+ ::<$($g),+>::$v { .. } => {}
+                _ => {}
+            }
+        }
+        $crate::name_of!(@last $($p)::+)
+    }};
+
+    // Covers Tuple Variants
+    ($($p: ident)::+ $(:: < $($g: ty),+ > :: $v: ident)* (..)) => {
+        $crate::enum_name_of!($($p)::+ $(::<$($g),+>::$v)*)
+    };
+
+    // Covers Struct Variants
+    ($($p: ident)::+ $(:: < $($g: ty),+ > :: $v: ident)* { .. }) => {
+        $crate::enum_name_of!($($p)::+ $(::<$($g),+>::$v)*)
+    };
+
+    // Extracts the second to last segment of a path
+    (@enum $e: ident :: $v: ident) => {
+        stringify!($e)
+    };
+
+    (@enum $head: ident :: $($tail: ident)::+) => {
+        $crate::enum_name_of!(@enum $($tail)::+)
+    };
+}
+
+/// Takes a path to an enum variant and returns the name of the variant,
+/// e.g. `tag_of!(Color::Rgb(..))` returns `"Rgb"`.
+///
+/// This accepts the same forms as `qualified_tag_of!()` and renders them the same way,
+/// except that the name of the enum is omitted, e.g. `tag_of!(Option::<u8>::None)` returns `"None"`
+/// and `tag_of!(Result::<u8, ()>::Ok(1))` returns `"Ok(1)"`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// enum Color {
+///     Red,
+///     Rgb(u8, u8, u8),
+///     Hsl { h: u16, s: u8, l: u8 },
+/// }
+///
+/// assert_eq!(tag_of!(Color::Red), "Red");
+/// assert_eq!(tag_of!(Color::Rgb(..)), "Rgb");
+/// assert_eq!(tag_of!(Color::Hsl { .. }), "Hsl");
+/// assert_eq!(tag_of!(Option::<u8>::None), "None");
+///
+/// # }
+/// ```
+///
+/// Rendering values requires the `alloc` feature:
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # #[cfg(feature = "alloc")]
+/// # fn main() {
+/// enum Color {
+///     Rgb(u8, u8, u8),
+///     Hsl { h: u16, s: u8, l: u8 },
+/// }
+///
+/// let (r, g, b) = (255, 128, 0);
+///
+/// assert_eq!(tag_of!(Color::Rgb(r, g, b)), "Rgb(255, 128, 0)");
+/// assert_eq!(tag_of!(Color::Hsl { h: 240, s: 100, l: 50 }), "Hsl { h: 240, s: 100, l: 50 }");
+/// assert_eq!(tag_of!(Result::<u8, ()>::Ok(1)), "Ok(1)");
+///
+/// # }
+/// # #[cfg(not(feature = "alloc"))]
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! tag_of {
+    // Covers all forms, which are rendered together with qualified_tag_of!
+    ($($t: tt)+) => {
+        $crate::__tag_of!([unqualified] [str] $($t)+)
+    };
+}
+
+/// Takes a path to an enum variant and returns its name qualified by the enum,
+/// e.g. `qualified_tag_of!(Color::Rgb(..))` returns `"Color::Rgb"`.
+///
+/// The variant is validated the same way as in `enum_name_of!()`. When a variant is given
+/// together with its values, a `String` is returned that includes the `Debug` representation of each value,
+/// e.g. `qualified_tag_of!(Color::Rgb(r, g, b))` returns `"Color::Rgb(255, 128, 0)"`. The fields of struct
+/// variants are rendered in the order they are given, e.g. `qualified_tag_of!(Color::Hsl { h: 240, s: 100, l: 50 })`
+/// returns `"Color::Hsl { h: 240, s: 100, l: 50 }"`. The values are borrowed, not moved, and formatting them
+/// requires the `alloc` feature, which is enabled by default.
+/// All values must be given, since the variant is validated by constructing it.
+/// Module paths in front of the enum are validated but omitted from the result,
+/// e.g. `qualified_tag_of!(crate::Color::Red)` returns `"Color::Red"`, and so are the type
+/// arguments of generic enums, e.g. `qualified_tag_of!(Option::<u8>::Some(..))` returns `"Option::Some"`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// enum Color {
+///     Red,
+///     Rgb(u8, u8, u8),
+///     Hsl { h: u16, s: u8, l: u8 },
+/// }
+///
+/// assert_eq!(qualified_tag_of!(Color::Red), "Color::Red");
+/// assert_eq!(qualified_tag_of!(Color::Rgb(..)), "Color::Rgb");
+/// assert_eq!(qualified_tag_of!(Color::Hsl { .. }), "Color::Hsl");
+///
+/// # }
+/// ```
+///
+/// Rendering values requires the `alloc` feature:
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # #[cfg(feature = "alloc")]
+/// # fn main() {
+/// enum Color {
+///     Rgb(u8, u8, u8),
+///     Hsl { h: u16, s: u8, l: u8 },
+/// }
+///
+/// let (r, g, b) = (255, 128, 0);
+///
+/// assert_eq!(qualified_tag_of!(Color::Rgb(r, g, b)), "Color::Rgb(255, 128, 0)");
+/// assert_eq!(
+///     qualified_tag_of!(Color::Hsl { h: 240, s: 100, l: 50 }),
+///     "Color::Hsl { h: 240, s: 100, l: 50 }"
+/// );
+///
+/// # }
+/// # #[cfg(not(feature = "alloc"))]
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! qualified_tag_of {
+    // Covers all forms, which are rendered together with tag_of!
+    ($($t: tt)+) => {
+        $crate::__tag_of!([qualified] [str] $($t)+)
+    };
+}
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __format_tag {
+    ($($a: tt)+) => {
+        $crate::__format_tag(format_args!($($a)+))
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __format_tag {
+    ($($a: tt)+) => {
+        compile_error!("rendering the values of enum variants requires the `alloc` feature, use `tag_of_into!()` or `qualified_tag_of_into!()` instead")
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tag_of {
+    // Covers Variants
+    ([$m: ident] [$($o: tt)*] $($p: ident)::+) => {{
+        let _ = $crate::enum_name_of!($($p)::+);
+        $crate::__tag_of!(@name [$($o)*] $crate::__tag_of!(@tag [$m] $($p)::+))
+    }};
+
+    // Covers Variants of Generic Enums
+    ([$m: ident] [$($o: tt)*] $($p: ident)::+ :: < $($g: ty),+ > :: $v: ident) => {{
+        let _ = $crate::enum_name_of!($($p)::+ ::<$($g),+>::$v);
+        $crate::__tag_of!(@name [$($o)*] $crate::__tag_of!(@tag [$m] $($p)::+ :: $v))
+    }};
+
+    // Covers Tuple Variants
+    ([$m: ident] [$($o: tt)*] $($p: ident)::+ $(:: < $($g: ty),+ > :: $v: ident)* (..)) => {
+        $crate::__tag_of!([$m] [$($o)*] $($p)::+ $(::<$($g),+>::$v)*)
+    };
+
+    // Covers Struct Variants
+    ([$m: ident] [$($o: tt)*] $($p: ident)::+ $(:: < $($g: ty),+ > :: $v: ident)* { .. }) => {
+        $crate::__tag_of!([$m] [$($o)*] $($p)::+ $(::<$($g),+>::$v)*)
+    };
+
+    // Covers Tuple Variants with Values
+    ([$m: ident] [$($o: tt)*] $($p: ident)::+ $(:: < $($g: ty),+ > :: $v: ident)* (
+        $x: expr $(, $xs: expr)* $(,)*
+    )) => {{
+        // The variant is constructed from placeholders of the same types,
+        // so that the values are not moved before they are formatted.
+        if false {
+            let _ = $($p)::+ $(::<$($g),+>::$v)* (
+                $crate::__placeholder_of(&$x) $(, $crate::__placeholder_of(&$xs))*
+            );
+        }
+        $crate::__tag_of!(
+            @format [$($o)*]
+            concat!("{}({:?}", $($crate::__tag_of!(@value $xs),)* ")"),
+            $crate::__tag_of!(@tag [$m] $($p)::+ $(:: $v)*),
+            $x $(, $xs)*
+        )
+    }};
+
+    // Covers Struct Variants with Values
+    ([$m: ident] [$($o: tt)*] $($p: ident)::+ $(:: < $($g: ty),+ > :: $v: ident)* {
+        $f: ident : $x: expr $(, $fs: ident : $xs: expr)* $(,)*
+    }) => {{
+        if false {
+            let _ = $($p)::+ $(::<$($g),+>::$v)* {
+                $f: $crate::__placeholder_of(&$x) $(, $fs: $crate::__placeholder_of(&$xs))*
+            };
+        }
+        $crate::__tag_of!(
+            @format [$($o)*]
+            concat!("{} {{ {}: {:?}", $($crate::__tag_of!(@field $fs),)* " }}"),
+            $crate::__tag_of!(@tag [$m] $($p)::+ $(:: $v)*),
+            $crate::__strip_raw!(stringify!($f)), $x
+            $(, $crate::__strip_raw!(stringify!($fs)), $xs)*
+        )
+    }};
+
+    // Returns or writes the name of the variant
+    (@name [str] $n: expr) => {
+        $n
+    };
+
+    (@name [into $w: expr] $n: expr) => {
+        $crate::__FmtWrite::write_str($w, $n)
+    };
+
+    // Formats or writes the variant together with its values
+    (@format [str] $($a: tt)+) => {
+        $crate::__format_tag!($($a)+)
+    };
+
+    (@format [into $w: expr] $($a: tt)+) => {
+        $crate::__FmtWrite::write_fmt($w, format_args!($($a)+))
+    };
+
+    (@value $x: expr) => {
+        ", {:?}"
+    };
+
+    (@field $f: ident) => {
+        ", {}: {:?}"
+    };
+
+    // Returns the name of the variant without raw identifier prefixes
+    (@tag [$m: ident] $($p: ident)::+) => {
+        $crate::__strip_raw!($crate::__tag_of!(@path [$m] $($p)::+))
+    };
+
+    // Extracts the last two segments of a path
+    (@path [qualified] $e: ident :: $v: ident) => {
+        concat!(stringify!($e), "::", stringify!($v))
+    };
+
+    (@path [qualified] $head: ident :: $($tail: ident)::+) => {
+        $crate::__tag_of!(@path [qualified] $($tail)::+)
+    };
+
+    // Extracts the last segment of a path
+    (@path [unqualified] $v: ident) => {
+        stringify!($v)
+    };
+
+    (@path [unqualified] $head: ident :: $($tail: ident)::+) => {
+        $crate::__tag_of!(@path [unqualified] $($tail)::+)
+    };
+}
+
+/// Takes a writer followed by a path to an enum variant, accepting the same forms as
+/// `tag_of!()`, and writes the tag into the writer,
+/// e.g. `tag_of_into!(&mut buffer, Color::Rgb(1, 2, 3))` writes `"Rgb(1, 2, 3)"`.
+///
+/// This is the unqualified counterpart of `qualified_tag_of_into!()` and behaves the same way,
+/// i.e. the values are formatted directly into the writer without allocating, so the `alloc` feature
+/// is not required.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// enum Color {
+///     Rgb(u8, u8, u8),
+/// }
+///
+/// let mut text = String::new();
+/// tag_of_into!(&mut text, Color::Rgb(1, 2, 3)).unwrap();
+///
+/// assert_eq!(text, "Rgb(1, 2, 3)");
+///
+/// # }
+/// ```
+#[macro_export]
+macro_rules! tag_of_into {
+    // Covers all forms supported by tag_of!
+    ($w: expr, $($t: tt)+) => {
+        $crate::__tag_of!([unqualified] [into $w] $($t)+)
+    };
+}
+
+/// Takes a writer followed by a path to an enum variant, accepting the same forms as
+/// `qualified_tag_of!()`, and writes the qualified tag into the writer,
+/// e.g. `qualified_tag_of_into!(&mut buffer, Color::Rgb(1, 2, 3))` writes `"Color::Rgb(1, 2, 3)"`.
+///
+/// The writer must be a mutable reference to a type implementing `core::fmt::Write`, and the
+/// `core::fmt::Result` of the write is returned. The values of variants are formatted directly
+/// into the writer, so nothing is allocated, which allows formatting tags into fixed-size buffers
+/// in `no_std` environments without the `alloc` feature. Like `write!()`, the tag may have been written
+/// partially when an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// enum Color {
+///     Rgb(u8, u8, u8),
+/// }
+///
+/// let mut text = String::new();
+/// qualified_tag_of_into!(&mut text, Color::Rgb(1, 2, 3)).unwrap();
+///
+/// assert_eq!(text, "Color::Rgb(1, 2, 3)");
+///
+/// # }
+/// ```
+#[macro_export]
+macro_rules! qualified_tag_of_into {
+    // Covers all forms supported by qualified_tag_of!
+    ($w: expr, $($t: tt)+) => {
+        $crate::__tag_of!([qualified] [into $w] $($t)+)
+    };
+}
+
+/// Takes a binding and returns a tuple of its name and a reference to its value,
+/// e.g. `name_and_value_of!(count)` returns `("count", &count)`.
+///
+/// The value is borrowed, not moved, so the macro works with both `Copy` and non-`Copy` types.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// let count = 42;
+///
+/// let (name, value) = name_and_value_of!(count);
+/// println!("Binding `{}` holds `{}`.", name, value);
+///
+/// # }
+/// ```
+#[macro_export]
+macro_rules! name_and_value_of {
+    // Covers Bindings
+    ($n: ident) => {
+        ($crate::name_of!($n), &$n)
+    };
+}
+
+/// Takes a comma-separated list of bindings and returns an array of tuples, each pairing
+/// the name of a binding with a reference to its value,
+/// e.g. `names_map_of!(width, height)` returns `[("width", &width), ("height", &height)]`.
+///
+/// All bindings must be of the same type. The values are borrowed, not moved,
+/// which makes the result suitable for building lookup tables or dumping related bindings.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// let width = 1920;
+/// let height = 1080;
+///
+/// for (name, value) in names_map_of!(width, height).iter() {
+///     println!("Binding `{}` holds `{}`.", name, value);
+/// }
+///
+/// # }
+/// ```
+#[macro_export]
+macro_rules! names_map_of {
+    // Covers Bindings
+    ($($n: ident),+ $(,)*) => {
+        [$($crate::name_and_value_of!($n)),+]
+    };
+}
+
+/// Takes a binding and returns a `String` containing its name and its `Debug` representation,
+/// e.g. `debug_name_of!(count)` returns `"count = 42"`.
+///
+/// The type of the binding must implement `Debug`. Since the result is allocated,
+/// this macro requires the `alloc` feature, which is enabled by default.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// let count = 42;
+///
+/// assert_eq!(debug_name_of!(count), "count = 42");
+///
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! debug_name_of {
+    // Covers Bindings
+    ($n: ident) => {
+        $crate::__debug_name_of($crate::name_of!($n), &$n)
+    };
+}
+
+/// Takes an expression and returns the name of its type as provided by
+/// `std::any::type_name()`, e.g. `type_name_of_val!(1.0f64)` returns `"f64"`.
+///
+/// The expression is evaluated and borrowed, but not consumed.
+/// Note that, unlike `name_of_type!()`, the returned name is fully qualified,
+/// e.g. `"alloc::string::String"`, and its exact format is not guaranteed to be stable.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// let text = String::from("Hello, World!");
+///
+/// println!("Binding `{}` is of type `{}`.", name_of!(text), type_name_of_val!(text));
+/// println!("Literal is of type `{}`.", type_name_of_val!(1.0f64));
+///
+/// # }
+/// ```
+#[macro_export]
+macro_rules! type_name_of_val {
+    // Covers Expressions
+    ($e: expr) => {
+        $crate::__type_name_of_val(&$e)
+    };
+}
+
+/// Takes a field in the same form as `name_of!(some_field in SomeType)` and returns a tuple
+/// of its name and its offset in bytes as provided by `std::mem::offset_of!()`,
+/// e.g. `offset_name_of!(y in Point)` returns `("y", 4)` for a `#[repr(C)]` struct
+/// consisting of two `i32` fields.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate nameof;
+/// # fn main() {
+/// #[repr(C)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// assert_eq!(offset_name_of!(x in Point), ("x", 0));
+/// assert_eq!(offset_name_of!(y in Point), ("y", 4));
+///
+/// # }
+/// ```
+#[macro_export]
+macro_rules! offset_name_of {
+    // Covers Fields
+    ($n: tt in $t: ty) => {
+        ($crate::name_of!($n in $t), $crate::__offset_of!($t, $n))
+    };
+}
+
+/// Provides the names of all fields of a struct, which can be accessed using `all_field_names_of!()`.
+///
+/// If the `derive` feature is enabled, this trait can be derived using `#[derive(FieldNames)]`.
+/// Fields of tuple structs are named by their index, e.g. `["0", "1"]`.
+pub trait FieldNames {
+    /// The names of all fields in declaration order.
+    const FIELD_NAMES: &'static [&'static str];
+}
+
+/// Provides the names of all fields of a struct together with the names under which they are
+/// serialized, which can be accessed using `serde_name_of!()`.
+///
+/// If the `serde` feature is enabled, this trait can be derived using `#[derive(SerdeFieldNames)]`,
+/// which reads the `#[serde(rename = "...")]` attributes of the fields and `#[serde(rename_all = "...")]` of the struct.
+#[cfg(feature = "serde")]
+pub trait SerdeFieldNames {
+    /// Pairs of the names of all fields and their serialized names in declaration order.
+    const SERDE_FIELD_NAMES: &'static [(&'static str, &'static str)];
+}
+
+/// A name returned by `name_token_of!()`, which derefs to its string representation.
+///
+/// Names compare equal to strings, e.g. `name_token_of!(some_binding) == "some_binding"`,
+/// and are displayed as the plain name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Name(&'static str);
+
+impl Name {
+    /// Creates a name from its string representation.
+    pub const fn new(name: &'static str) -> Self {
+        Name(name)
+    }
+
+    /// Returns the string representation of the name.
+    pub const fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl core::ops::Deref for Name {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.0
+    }
+}
+
+impl AsRef<str> for Name {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl core::fmt::Display for Name {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl PartialEq<str> for Name {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Name {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<Name> for str {
+    fn eq(&self, other: &Name) -> bool {
+        self == other.0
+    }
+}
+
+impl PartialEq<Name> for &str {
+    fn eq(&self, other: &Name) -> bool {
+        *self == other.0
+    }
+}
+
+impl From<Name> for &'static str {
+    fn from(name: Name) -> Self {
+        name.0
+    }
+}
+
+#[doc(hidden)]
+pub trait __Callable<Args> {}
+
+macro_rules! impl_callable {
+    ($($a: ident),*) => {
+        impl<F, R, $($a),*> __Callable<($($a,)*)> for F where F: FnOnce($($a),*) -> R {}
+    };
+}
+
+impl_callable!();
+impl_callable!(A);
+impl_callable!(A, B);
+impl_callable!(A, B, C);
+impl_callable!(A, B, C, D);
+impl_callable!(A, B, C, D, E);
+impl_callable!(A, B, C, D, E, G);
+impl_callable!(A, B, C, D, E, G, H);
+impl_callable!(A, B, C, D, E, G, H, I);
+impl_callable!(A, B, C, D, E, G, H, I, J);
+impl_callable!(A, B, C, D, E, G, H, I, J, K);
+impl_callable!(A, B, C, D, E, G, H, I, J, K, L);
+impl_callable!(A, B, C, D, E, G, H, I, J, K, L, M);
+
+#[doc(hidden)]
+pub const fn __assert_callable<F, Args>(_: &F)
+where
+    F: __Callable<Args>,
+{
+}
+
+#[doc(hidden)]
+pub use core::mem::offset_of as __offset_of;
+
+#[doc(hidden)]
+pub const fn __forget<T>(value: T) {
+    core::mem::forget(value);
+}
+
+#[doc(hidden)]
+pub use core::ptr::addr_of as __addr_of;
+
+#[doc(hidden)]
+pub use core::fmt::Write as __FmtWrite;
+
+#[doc(hidden)]
+pub use core::marker::PhantomData as __PhantomData;
+
+#[doc(hidden)]
+pub use core::any::type_name as __type_name;
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub fn __debug_name_of<T: ?Sized + core::fmt::Debug>(
+    name: &str,
+    value: &T,
+) -> alloc::string::String {
+    alloc::format!("{} = {:?}", name, value)
+}
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub fn __format_tag(args: core::fmt::Arguments) -> alloc::string::String {
+    alloc::fmt::format(args)
+}
+
+#[doc(hidden)]
+#[allow(clippy::empty_loop)]
+pub fn __placeholder_of<T>(_: &T) -> T {
+    loop {}
+}
+
+#[doc(hidden)]
+pub fn __type_name_of_val<T: ?Sized>(_: &T) -> &'static str {
+    core::any::type_name::<T>()
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __transform_str {
+    ($s: expr, $len: ident, $transform: ident $(, $arg: expr)*) => {{
+        // Items declared by macros are visible to the input, so the input is bound
+        // before the helper constants are declared within a nested block.
+        const __NAMEOF_RAW: &str = $s;
+        {
+            const __NAMEOF_LEN: usize = $crate::$len(__NAMEOF_RAW $(, $arg)*);
+            const __NAMEOF_BYTES: [u8; __NAMEOF_LEN] = $crate::$transform(__NAMEOF_RAW $(, $arg)*);
+            const __NAMEOF_NAME: &str = $crate::__str_from_utf8(&__NAMEOF_BYTES);
+            __NAMEOF_NAME
+        }
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_str {
+    ($a: expr, $b: expr) => {{
+        const __NAMEOF_SUFFIX: &str = $b;
+        $crate::__transform_str!($a, __concatenated_len, __concatenate, __NAMEOF_SUFFIX)
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __normalize_type {
+    ($s: expr) => {
+        $crate::__transform_str!($s, __normalized_type_len, __normalize_type)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __strip_raw {
+    ($s: expr) => {
+        $crate::__transform_str!($s, __stripped_raw_len, __strip_raw)
+    };
+}
+
+// Validates a list of trait bounds, e.g. `Iterator<Item = T> + Send + 'a`.
+//
+// Nested items cannot refer to the generic parameters of the enclosing function, so each trait
+// is validated by the where clause of a type alias whose generic arguments are replaced by a
+// placeholder parameter. The bounds of type aliases are resolved but not enforced, so any trait
+// is accepted regardless of the bounds on its parameters, while the arguments themselves are
+// validated as types within the enclosing function. Associated types are validated likewise by
+// projecting the placeholder onto the trait. Bounds that cannot be taken apart,
+// such as higher-ranked bounds, are validated by a nested function instead.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __validate_bound {
+    ([$($k: tt)*]) => {};
+
+    ([$($k: tt)*] + $($r: tt)*) => {
+        $crate::__validate_bound!([$($k)*] $($r)*);
+    };
+
+    ([$($k: tt)*] $l: lifetime $($r: tt)*) => {
+        let _: &$l ();
+        $crate::__validate_bound!([$($k)*] $($r)*);
+    };
+
+    ([$($k: tt)*] ? $s: ident $($r: tt)*) => {
+        $crate::__validate_bound!([$($k)*] $($r)*);
+    };
+
+    ([$($k: tt)*] for $($r: tt)+) => {
+        $crate::__validate_bound!(@nested [$($k)*] for $($r)+);
+    };
+
+    ([$($k: tt)*] :: $($r: tt)+) => {
+        $crate::__validate_bound!(@path [$($k)*] [:: $($r)+] [::] $($r)+);
+    };
+
+    ([$($k: tt)*] $($r: tt)+) => {
+        $crate::__validate_bound!(@path [$($k)*] [$($r)+] [] $($r)+);
+    };
+
+    // Collects the path of the trait
+    (@path [$($k: tt)*] [$($o: tt)*] [$($p: tt)*] $i: ident :: $($r: tt)+) => {
+        $crate::__validate_bound!(@path [$($k)*] [$($o)*] [$($p)* $i ::] $($r)+);
+    };
+
+    (@path [$($k: tt)*] [$($o: tt)*] [$($p: tt)*] $i: ident < $($r: tt)+) => {
+        $crate::__validate_bound!(@args [$($k)*] [$($o)*] [$($p)* $i] [] [] $($r)+);
+    };
+
+    (@path [$($k: tt)*] [$($o: tt)*] [$($p: tt)*] $i: ident $(+ $($r: tt)*)?) => {
+        $crate::__validate_bound!(@alias [$($k)*] [$($p)* $i] [] []);
+        $crate::__validate_bound!([$($k)*] $($($r)*)?);
+    };
+
+    (@path [$($k: tt)*] [$($o: tt)*] $($r: tt)*) => {
+        $crate::__validate_bound!(@nested [$($k)*] $($o)*);
+    };
+
+    // Collects the generic arguments and the associated type bindings of the trait,
+    // replacing types by the placeholder
+    (@args [$($k: tt)*] [$($o: tt)*] [$($p: tt)*] [$($d: tt)*] [$($b: tt)*] > $($r: tt)*) => {
+        $crate::__validate_bound!(@alias [$($k)*] [$($p)*] [$($d)*] [$($b)*]);
+        $crate::__validate_bound!([$($k)*] $($r)*);
+    };
+
+    (@args [$($k: tt)*] [$($o: tt)*] [$($p: tt)*] [$($d: tt)*] [$($b: tt)*] $l: lifetime , $($r: tt)*) => {
+        let _: &$l ();
+        $crate::__validate_bound!(@args [$($k)*] [$($o)*] [$($p)*] [$($d)* 'static,] [$($b)*] $($r)*);
+    };
+
+    (@args [$($k: tt)*] [$($o: tt)*] [$($p: tt)*] [$($d: tt)*] [$($b: tt)*] $l: lifetime > $($r: tt)*) => {
+        let _: &$l ();
+        $crate::__validate_bound!(@args [$($k)*] [$($o)*] [$($p)*] [$($d)* 'static,] [$($b)*] > $($r)*);
+    };
+
+    (@args [$($k: tt)*] [$($o: tt)*] [$($p: tt)*] [$($d: tt)*] [$($b: tt)*] $c: literal , $($r: tt)*) => {
+        $crate::__validate_bound!(@args [$($k)*] [$($o)*] [$($p)*] [$($d)* $c,] [$($b)*] $($r)*);
+    };
+
+    (@args [$($k: tt)*] [$($o: tt)*] [$($p: tt)*] [$($d: tt)*] [$($b: tt)*] $c: literal > $($r: tt)*) => {
+        $crate::__validate_bound!(@args [$($k)*] [$($o)*] [$($p)*] [$($d)* $c,] [$($b)*] > $($r)*);
+    };
+
+    (@args [$($k: tt)*] [$($o: tt)*] [$($p: tt)*] [$($d: tt)*] [$($b: tt)*] $n: ident = $a: ty , $($r: tt)*) => {
+        let _: $crate::__PhantomData<$a>;
+        $crate::__validate_bound!(@args [$($k)*] [$($o)*] [$($p)*] [$($d)*] [$($b)* $n = __NameOfA,] $($r)*);
+    };
+
+    (@args [$($k: tt)*] [$($o: tt)*] [$($p: tt)*] [$($d: tt)*] [$($b: tt)*] $n: ident = $a: ty > $($r: tt)*) => {
+        let _: $crate::__PhantomData<$a>;
+        $crate::__validate_bound!(@args [$($k)*] [$($o)*] [$($p)*] [$($d)*] [$($b)* $n = __NameOfA,] > $($r)*);
+    };
+
+    (@args [$($k: tt)*] [$($o: tt)*] [$($p: tt)*] [$($d: tt)*] [$($b: tt)*] $a: ty , $($r: tt)*) => {
+        let _: $crate::__PhantomData<$a>;
+        $crate::__validate_bound!(@args [$($k)*] [$($o)*] [$($p)*] [$($d)* __NameOfA,] [$($b)*] $($r)*);
+    };
+
+    (@args [$($k: tt)*] [$($o: tt)*] [$($p: tt)*] [$($d: tt)*] [$($b: tt)*] $a: ty > $($r: tt)*) => {
+        let _: $crate::__PhantomData<$a>;
+        $crate::__validate_bound!(@args [$($k)*] [$($o)*] [$($p)*] [$($d)* __NameOfA,] [$($b)*] > $($r)*);
+    };
+
+    (@args [$($k: tt)*] [$($o: tt)*] $($r: tt)*) => {
+        $crate::__validate_bound!(@nested [$($k)*] $($o)*);
+    };
+
+    // Validates a trait by a type alias
+    (@alias [dyn] [$($p: tt)*] [$($d: tt)*] [$($b: tt)*]) => {
+        {
+            #[allow(dead_code, type_alias_bounds)]
+            type __NameOfBound<__NameOfA> where __NameOfA: $($p)* <$($d)* $($b)*> = __NameOfA;
+        }
+    };
+
+    // Validates an associated type of a trait by projecting the placeholder onto it
+    (@alias [type $n: ident] [$($p: tt)*] [$($d: tt)*] [$($b: tt)*]) => {
+        {
+            #[allow(dead_code, type_alias_bounds)]
+            type __NameOfBound<__NameOfA> where __NameOfA: $($p)* <$($d)* $($b)*> =
+                <__NameOfA as $($p)* <$($d)*>>::$n;
+        }
+    };
+
+    // Validates an associated constant of a trait by a nested function, as constants cannot be
+    // referred to by types. The placeholder is a parameter of the function in this case,
+    // so bounds on the parameters of the trait are enforced.
+    (@alias [const $n: ident] [$($p: tt)*] [$($d: tt)*] [$($b: tt)*]) => {
+        {
+            #[allow(dead_code, clippy::extra_unused_type_parameters)]
+            fn f<__NameOfT: $($p)* <$($d)* $($b)*>, __NameOfA>() {
+                let _ = &<__NameOfT as $($p)* <$($d)*>>::$n;
+            }
+        }
+    };
+
+    // Validates the remaining bounds by a nested function
+    (@nested [dyn] $($b: tt)+) => {
+        {
+            #[allow(dead_code)]
+            fn f<__NameOfT: $($b)+>() {}
+        }
+    };
+
+    (@nested [const $n: ident] $($b: tt)+) => {
+        {
+            #[allow(dead_code)]
+            fn f<__NameOfT: $($b)+>() {
+                let _ = &<__NameOfT as $($b)+>::$n;
+            }
+        }
+    };
+
+    (@nested [type $n: ident] $($b: tt)+) => {
+        {
+            #[allow(dead_code)]
+            fn f<__NameOfT: $($b)+>(_: &<__NameOfT as $($b)+>::$n) {}
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __convert_case {
+    ($s: expr, $separator: expr, $uppercase: expr) => {
+        $crate::__transform_str!(
+            $s,
+            __converted_case_len,
+            __convert_case,
+            $separator,
+            $uppercase
+        )
+    };
+}
+
+const fn is_whitespace(c: u8) -> bool {
+    c == b' ' || c == b'\t' || c == b'\n' || c == b'\r'
+}
+
+const fn is_ident_char(c: u8) -> bool {
+    c == b'_' || c.is_ascii_alphanumeric() || c >= 0x80
+}
+
+const fn is_keyword_before(s: &[u8], end: usize) -> bool {
+    const KEYWORDS: [&[u8]; 5] = [b"dyn", b"impl", b"as", b"mut", b"const"];
+
+    let mut start = end;
+    while start > 0 && is_ident_char(s[start - 1]) {
+        start -= 1;
+    }
+
+    let mut k = 0;
+    while k < KEYWORDS.len() {
+        let keyword = KEYWORDS[k];
+        if keyword.len() == end - start {
+            let mut i = 0;
+            while i < keyword.len() && keyword[i] == s[start + i] {
+                i += 1;
+            }
+
+            if i == keyword.len() {
+                return true;
+            }
+        }
+
+        k += 1;
+    }
+
+    false
+}
+
+const fn is_space_required(s: &[u8], prev: usize, next: usize) -> bool {
+    let p = s[prev];
+    let n = s[next];
+
+    if p == b'>' && prev > 0 && s[prev - 1] == b'-' {
+        return true;
+    }
+
+    if p == b'<' || p == b'(' || p == b'[' || p == b'&' {
+        return false;
+    }
+
+    if p == b':' && prev > 0 && s[prev - 1] == b':' {
+        return false;
+    }
+
+    if n == b'>' || n == b')' || n == b']' || n == b',' || n == b';' {
+        return false;
+    }
+
+    if (n == b'<' || (n == b':' && next + 1 < s.len() && s[next + 1] == b':'))
+        && (p == b'>' || (is_ident_char(p) && !is_keyword_before(s, prev + 1)))
+    {
+        return false;
+    }
+
+    true
+}
+
+const fn is_binary_operator(s: &[u8], i: usize) -> bool {
+    let c = s[i];
+    if c != b'*' && c != b'/' && c != b'%' && c != b'-' {
+        return false;
+    }
+
+    if c == b'-' && i + 1 < s.len() && s[i + 1] == b'>' {
+        return false;
+    }
+
+    let mut prev = i;
+    while prev > 0 && is_whitespace(s[prev - 1]) {
+        prev -= 1;
+    }
+
+    if prev == 0 {
+        return false;
+    }
+
+    let p = s[prev - 1];
+    if !is_ident_char(p) && p != b')' && p != b']' && p != b'}' {
+        return false;
+    }
+
+    let mut next = i + 1;
+    while next < s.len() && is_whitespace(s[next]) {
+        next += 1;
+    }
+
+    // Raw pointers such as `&'a *const T` are not binary operators.
+    !(c == b'*' && (starts_with_word(s, next, b"const") || starts_with_word(s, next, b"mut")))
+}
+
+const fn starts_with_word(s: &[u8], i: usize, word: &[u8]) -> bool {
+    if i + word.len() > s.len() {
+        return false;
+    }
+
+    let mut j = 0;
+    while j < word.len() {
+        if s[i + j] != word[j] {
+            return false;
+        }
+        j += 1;
+    }
+
+    i + word.len() == s.len() || !is_ident_char(s[i + word.len()])
+}
+
+const fn push<const N: usize>(mut buffer: [u8; N], len: usize, c: u8) -> ([u8; N], usize) {
+    if len < N {
+        buffer[len] = c;
+    }
+
+    (buffer, len + 1)
+}
+
+const fn normalize_type<const N: usize>(s: &[u8]) -> ([u8; N], usize) {
+    let mut buffer = [0u8; N];
+    let mut len = 0;
+    let mut i = 0;
+
+    while i < s.len() {
+        let c = s[i];
+
+        if is_whitespace(c) {
+            let mut next = i;
+            while next < s.len() && is_whitespace(s[next]) {
+                next += 1;
+            }
+
+            if i > 0 && next < s.len() && is_space_required(s, i - 1, next) {
+                let (b, l) = push(buffer, len, b' ');
+                buffer = b;
+                len = l;
+            }
+
+            i = next;
+            continue;
+        }
+
+        let is_arrow = c == b'-' && i + 1 < s.len() && s[i + 1] == b'>';
+        let is_operator = is_arrow || c == b'+' || c == b'=' || is_binary_operator(s, i);
+
+        if (is_operator || (c == b'}' && i > 0 && s[i - 1] != b'{'))
+            && i > 0
+            && !is_whitespace(s[i - 1])
+        {
+            let (b, l) = push(buffer, len, b' ');
+            buffer = b;
+            len = l;
+        }
+
+        let (b, l) = push(buffer, len, c);
+        buffer = b;
+        len = l;
+
+        if is_arrow {
+            let (b, l) = push(buffer, len, b'>');
+            buffer = b;
+            len = l;
+            i += 1;
+        }
+
+        i += 1;
+
+        let is_separator = c == b',' || c == b';';
+        if (is_operator || is_separator || c == b'{') && i < s.len() {
+            let n = s[i];
+            if !is_whitespace(n)
+                && (is_operator || (n != b'>' && n != b')' && n != b']' && n != b'}'))
+            {
+                let (b, l) = push(buffer, len, b' ');
+                buffer = b;
+                len = l;
+            }
+        }
+    }
+
+    (buffer, len)
+}
+
+const fn unqualify<const N: usize>(s: &[u8]) -> ([u8; N], usize) {
+    let mut start = 0;
+    let mut i = 0;
+
+    // Skips all leading path segments, but not the separator of a turbofish such as `Vec::<u8>`.
+    while i < s.len() && (is_ident_char(s[i]) || s[i] == b':') {
+        if s[i] == b':' && i + 1 < s.len() && is_ident_char(s[i + 1]) {
+            start = i + 1;
+        }
+        i += 1;
+    }
+
+    let mut buffer = [0u8; N];
+    let mut len = 0;
+    i = start;
+
+    while i < s.len() {
+        let (b, l) = push(buffer, len, s[i]);
+        buffer = b;
+        len = l;
+        i += 1;
+    }
+
+    (buffer, len)
+}
+
+const fn shorten_type<const N: usize>(s: &[u8]) -> ([u8; N], usize) {
+    let mut end = 0;
+
+    // Only paths are shortened, i.e. when the first generic argument list follows a path.
+    while end < s.len() && (is_ident_char(s[end]) || s[end] == b':') {
+        end += 1;
+    }
+
+    if end == 0 || end == s.len() || s[end] != b'<' {
+        end = s.len();
+    } else if s[end - 1] == b':' {
+        // Drops the separator of a turbofish such as `Vec::<u8>`.
+        end -= 2;
+    }
+
+    let mut buffer = [0u8; N];
+    let mut len = 0;
+    let mut i = 0;
+
+    while i < end {
+        let (b, l) = push(buffer, len, s[i]);
+        buffer = b;
+        len = l;
+        i += 1;
+    }
+
+    (buffer, len)
+}
+
+const fn strip_raw<const N: usize>(s: &[u8]) -> ([u8; N], usize) {
+    let mut buffer = [0u8; N];
+    let mut len = 0;
+    let mut i = 0;
+
+    while i < s.len() {
+        let is_raw_prefix = s[i] == b'r' && i + 1 < s.len() && s[i + 1] == b'#';
+        if is_raw_prefix && (i == 0 || !is_ident_char(s[i - 1])) {
+            i += 2;
+            continue;
+        }
+
+        let (b, l) = push(buffer, len, s[i]);
+        buffer = b;
+        len = l;
+        i += 1;
+    }
+
+    (buffer, len)
+}
+
+const fn is_word_boundary(s: &[u8], i: usize) -> bool {
+    if i == 0 {
+        return false;
+    }
+
+    let p = s[i - 1];
+    let c = s[i];
+
+    if c.is_ascii_uppercase() && (p.is_ascii_lowercase() || p.is_ascii_digit()) {
+        return true;
+    }
+
+    c.is_ascii_uppercase()
+        && p.is_ascii_uppercase()
+        && i + 1 < s.len()
+        && s[i + 1].is_ascii_lowercase()
+}
+
+const fn convert_case<const N: usize>(
+    s: &[u8],
+    separator: u8,
+    uppercase: bool,
+) -> ([u8; N], usize) {
+    let mut buffer = [0u8; N];
+    let mut len = 0;
+    let mut i = 0;
+    let mut pending_separator = false;
+    let mut leading = true;
+
+    while i < s.len() {
+        let c = s[i];
+
+        // Leading underscores, e.g. of unused bindings, are retained as they are.
+        if c == b'_' && leading {
+            let (b, l) = push(buffer, len, c);
+            buffer = b;
+            len = l;
+            i += 1;
+            continue;
+        }
+
+        if c == b'_' || c == b'-' {
+            pending_separator = len > 0;
+            i += 1;
+            continue;
+        }
+
+        if len > 0 && (pending_separator || is_word_boundary(s, i)) {
+            let (b, l) = push(buffer, len, separator);
+            buffer = b;
+            len = l;
+        }
+
+        pending_separator = false;
+        leading = false;
+
+        let c = if uppercase {
+            c.to_ascii_uppercase()
+        } else {
+            c.to_ascii_lowercase()
+        };
+
+        let (b, l) = push(buffer, len, c);
+        buffer = b;
+        len = l;
+        i += 1;
+    }
+
+    (buffer, len)
+}
+
+#[doc(hidden)]
+pub const fn __normalized_type_len(s: &str) -> usize {
+    normalize_type::<0>(s.as_bytes()).1
+}
+
+#[doc(hidden)]
+pub const fn __normalize_type<const N: usize>(s: &str) -> [u8; N] {
+    normalize_type::<N>(s.as_bytes()).0
+}
+
+#[doc(hidden)]
+pub const fn __unqualified_len(s: &str) -> usize {
+    unqualify::<0>(s.as_bytes()).1
+}
+
+#[doc(hidden)]
+pub const fn __unqualify<const N: usize>(s: &str) -> [u8; N] {
+    unqualify::<N>(s.as_bytes()).0
+}
+
+#[doc(hidden)]
+pub const fn __shortened_type_len(s: &str) -> usize {
+    shorten_type::<0>(s.as_bytes()).1
+}
+
+#[doc(hidden)]
+pub const fn __shorten_type<const N: usize>(s: &str) -> [u8; N] {
+    shorten_type::<N>(s.as_bytes()).0
+}
+
+#[doc(hidden)]
+pub const fn __stripped_raw_len(s: &str) -> usize {
+    strip_raw::<0>(s.as_bytes()).1
+}
+
+#[doc(hidden)]
+pub const fn __strip_raw<const N: usize>(s: &str) -> [u8; N] {
+    strip_raw::<N>(s.as_bytes()).0
+}
+
+#[doc(hidden)]
+pub const fn __converted_case_len(s: &str, separator: u8, uppercase: bool) -> usize {
+    convert_case::<0>(s.as_bytes(), separator, uppercase).1
+}
+
+#[doc(hidden)]
+pub const fn __convert_case<const N: usize>(s: &str, separator: u8, uppercase: bool) -> [u8; N] {
+    convert_case::<N>(s.as_bytes(), separator, uppercase).0
+}
+
+#[doc(hidden)]
+pub const fn __str_eq(a: &str, b: &str) -> bool {
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+#[doc(hidden)]
+pub const fn __concatenated_len(a: &str, b: &str) -> usize {
+    a.len() + b.len()
+}
+
+#[doc(hidden)]
+pub const fn __concatenate<const N: usize>(a: &str, b: &str) -> [u8; N] {
+    let mut buffer = [0u8; N];
+    let mut i = 0;
+
+    while i < a.len() {
+        buffer[i] = a.as_bytes()[i];
+        i += 1;
+    }
+
+    while i < N {
+        buffer[i] = b.as_bytes()[i - a.len()];
+        i += 1;
+    }
+
+    buffer
+}
+
+#[doc(hidden)]
+pub const fn __cstr_from_str(s: &str) -> &core::ffi::CStr {
+    match core::ffi::CStr::from_bytes_with_nul(s.as_bytes()) {
+        Ok(s) => s,
+        Err(_) => panic!("name is not NUL-terminated or contains NUL"),
+    }
+}
+
+#[doc(hidden)]
+pub const fn __fnv1a_hash(s: &str) -> u64 {
+    let bytes = s.as_bytes();
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        i += 1;
+    }
+
+    hash
+}
+
+#[doc(hidden)]
+pub const fn __all_unique(names: &[&str]) -> bool {
+    let mut i = 0;
+    while i < names.len() {
+        let mut j = i + 1;
+        while j < names.len() {
+            if __str_eq(names[i], names[j]) {
+                return false;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+#[doc(hidden)]
+pub const fn __index_of(name: &str, names: &[&str]) -> usize {
+    let mut i = 0;
+    while i < names.len() {
+        if __str_eq(name, names[i]) {
+            return i;
+        }
+        i += 1;
+    }
+
+    panic!("name is not part of the list");
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub const fn __serde_name_of(
+    name: &str,
+    names: &'static [(&'static str, &'static str)],
+) -> &'static str {
+    let mut i = 0;
+    while i < names.len() {
+        if __str_eq(name, names[i].0) {
+            return names[i].1;
+        }
+        i += 1;
+    }
+
+    panic!("field is not part of the serialized names");
+}
+
+#[doc(hidden)]
+pub const fn __str_from_utf8(bytes: &[u8]) -> &str {
+    match core::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(_) => panic!("normalized name is not valid UTF-8"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::boxed::Box;
+    use std::collections::HashMap;
+    use std::string::String;
+    use std::vec;
+    use std::vec::Vec;
+
+    fn test_fn() {
+        //
+    }
+
+    static TEST_STATIC: u32 = 1;
+
+    const TEST_MODULE_CONST: &str = "test";
+    const TEST_ARRAY_LEN: usize = 16;
+
+    static mut TEST_STATIC_MUT: u8 = 0;
+
+    #[used]
+    #[cfg_attr(target_os = "linux", link_section = ".data")]
+    static TEST_LINKED_STATIC: u32 = 0;
+
+    #[used]
+    #[cfg_attr(target_os = "linux", link_section = ".data")]
+    static mut TEST_LINKED_STATIC_MUT: u32 = 0;
+
+    fn test_fn_with_args(_: i32, _: &str) -> bool {
+        true
+    }
+
+    fn test_generic_fn<T: Default>() -> T {
+        T::default()
+    }
+
+    mod test_module {
+        pub mod test_nested_module {}
+
+        #[allow(dead_code)]
+        pub struct TestModuleTupleStruct(pub i32, pub i32);
+
+        #[allow(dead_code)]
+        pub struct TestModuleStruct {
+            pub test_field: i32,
+        }
+
+        #[allow(dead_code)]
+        pub enum TestModuleEnum {
+            Unit,
+            Tuple(i32, i32),
+            Struct { test_field: i32 },
+        }
+    }
+
+    mod test_shadow {
+        #[allow(non_camel_case_types, dead_code)]
+        pub struct test_fn {}
+    }
+
+    struct TestStruct {
+        test_field: i32,
+    }
+
+    impl TestStruct {
+        const TEST_CONST: i32 = 1;
+        const TEST_ARRAY_LEN: usize = 8;
+    }
+
+    impl TestStruct {
+        fn test_field_name(&self) -> &'static str {
+            name_of!(self.test_field)
+        }
+
+        fn test_field_name_mut(&mut self) -> &'static str {
+            let _borrow = &mut self.test_field;
+            name_of!(self.test_field)
+        }
+
+        fn self_name() -> &'static str {
+            name_of_type!(Self)
+        }
+
+        fn test_generic_assoc_fn<T: Default>() -> T {
+            T::default()
+        }
+    }
+
+    trait TestTrait {
+        fn trait_self_name() -> &'static str;
+    }
+
+    impl TestTrait for TestStruct {
+        fn trait_self_name() -> &'static str {
+            name_of_type!(Self)
+        }
+    }
+
+    trait TestGenericTrait<T> {}
+
+    trait TestAssociatedConstTrait {
+        const TEST_ASSOCIATED_CONST: u32;
+        const TEST_DEFAULT_CONST: &'static str = "default";
+    }
+
+    trait TestGenericAssociatedConstTrait<T> {
+        const TEST_GENERIC_CONST: Option<T>;
+    }
+
+    trait TestAssociatedTypeTrait {
+        type TestAssociatedType: ?Sized;
+    }
+
+    impl TestAssociatedTypeTrait for TestStruct {
+        type TestAssociatedType = str;
+    }
+
+    #[allow(dead_code)]
+    #[non_exhaustive]
+    #[repr(u8)]
+    enum TestNonExhaustiveEnum {
+        #[non_exhaustive]
+        Unit,
+        #[non_exhaustive]
+        Tuple(u8, u8),
+        Struct {
+            test_field: u8,
+        },
+    }
+
+    #[allow(dead_code)]
+    enum TestEnum {
+        Red,
+        Green,
+        Blue,
+        Rgb(u8, u8, u8),
+        Hsl { h: u16, s: u8, l: u8 },
+    }
+
+    #[allow(dead_code)]
+    enum TestShapeEnum {
+        Point { x: i32, y: i32 },
+    }
+
+    #[allow(dead_code)]
+    enum TestComplexEnum {
+        Empty,
+        Boxed(Box<dyn Fn(u8) -> u8>, Vec<Option<&'static str>>),
+        Raw { r#type: u8 },
+    }
+
+    #[allow(dead_code)]
+    enum TestGenericEnum<T> {
+        Unit,
+        Tuple(T, T),
+        Struct { test_field: T },
+    }
+
+    struct TestRawStruct {
+        r#match: i32,
+        r#type: TestStruct,
+    }
+
+    #[repr(C)]
+    union TestUnion {
+        test_int: u32,
+        test_float: f32,
+    }
+
+    #[repr(C, packed)]
+    struct TestPackedStruct {
+        test_byte: u8,
+        test_int: u32,
+    }
+
+    struct TestTupleStruct(i32, i32);
+
+    struct TestDeref<T>(T);
+
+    impl<T> core::ops::Deref for TestDeref<T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            &self.0
+        }
+    }
+
+    impl TestTupleStruct {
+        fn second_field_name(self) -> &'static str {
+            name_of!(self.1)
+        }
+    }
+
+    impl TestRawStruct {
+        fn raw_field_name(&self) -> &'static str {
+            name_of!(self.r#type)
+        }
+    }
+
+    #[allow(dead_code)]
+    struct TestLargeTupleStruct(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);
+
+    struct TestNestedStruct<T> {
+        test_outer_field: TestGenericStruct<T>,
+    }
+
+    struct TestDeeplyNestedStruct {
+        test_deep_field: TestNestedStruct<TestStruct>,
+    }
+
+    struct TestGenericStruct<T> {
+        test_field: T,
+    }
+
+    struct TestConstGenericStruct<const R: usize, const C: usize> {
+        test_field: [[u8; C]; R],
+    }
+
+    struct TestGenericStructMultiType<T, U> {
+        test_field_t: T,
+        test_field_u: U,
+    }
+
+    impl<T> TestGenericStruct<T> {
+        const TEST_GENERIC_CONST: usize = 1;
+    }
+
+    impl<T> TestGenericStructMultiType<T, T>
+    where
+        T: Clone,
+    {
+        fn type_param_names() -> [&'static str; 1] {
+            [name_of!(type_param T)]
+        }
+    }
+
+    fn test_const_param_names<const N: usize, const FLAG: bool, const r#C: char>(
+    ) -> [&'static str; 3] {
+        [
+            name_of!(const_param N),
+            name_of!(const_param FLAG),
+            name_of!(const_param r#C),
+        ]
+    }
+
+    impl<const R: usize, const C: usize> TestConstGenericStruct<R, C> {
+        fn const_param_names() -> [&'static str; 2] {
+            [name_of!(const_param R), name_of!(const_param C)]
+        }
+    }
+
+    fn test_type_param_names<T, U: ?Sized, r#V>() -> [&'static str; 3] {
+        [
+            name_of!(type_param T),
+            name_of!(type_param U),
+            name_of!(type_param r#V),
+        ]
+    }
+
+    impl<T, U> TestGenericStructMultiType<T, U> {
+        const TEST_GENERIC_MULTI_TYPE_CONST: usize = 2;
+    }
+
+    const TEST_CONST_FN_NAME: &str = name_of!(test_fn);
+    const TEST_CONST_TYPE_NAME: &str = name_of!(type TestStruct);
+    const TEST_CONST_FIELD_NAME: &str = name_of!(test_field in TestStruct);
+    const TEST_CONST_CONST_NAME: &str = name_of!(const TEST_CONST in TestStruct);
+    const TEST_CONST_STATIC_MUT_NAME: &str = name_of!(static TEST_STATIC_MUT);
+    const TEST_CONST_TRAIT_NAME: &str = name_of!(trait TestTrait);
+    const TEST_CONST_MODULE_NAME: &str = name_of!(mod self::test_module::test_nested_module);
+    const TEST_CONST_FIELD_NAME_MACRO: &str = const_field_name!(test_field in TestStruct);
+    const TEST_CONST_TUPLE_FIELD_NAME: &str = const_field_name!(1 in TestTupleStruct);
+    const TEST_CONST_RAW_FIELD_NAME: &str = const_field_name!(r#type in TestRawStruct);
+    const TEST_CONST_NESTED_FIELD_NAME: &str =
+        const_field_name!(r#type.test_field in TestRawStruct);
+    static TEST_STATIC_FN_NAME: &str = name_of!(fn test_fn);
+    static TEST_STATIC_TYPE_NAME: &str = name_of_type!(TestStruct);
+
+    assert_unique_names!(test_fn, test_fn_with_args, TEST_CONST_FN_NAME);
+    assert_unique_names!(TEST_STATIC, TEST_ARRAY_LEN,);
+    const_assert_name!(test_fn, "test_fn");
+    const_assert_name!(type TestGenericStruct<i32>, "TestGenericStruct<i32>");
+
+    #[test]
+    fn name_of_binding() {
+        let test_variable = 123;
+        assert_eq!(name_of!(test_variable), "test_variable");
+    }
+
+    #[test]
+    fn name_of_repeated_many_times() {
+        macro_rules! repeat_10 {
+            ($($t: tt)*) => {
+                $($t)* $($t)* $($t)* $($t)* $($t)* $($t)* $($t)* $($t)* $($t)* $($t)*
+            };
+        }
+
+        let test_variable = 1;
+        let mut total = 0;
+
+        repeat_10! {
+            repeat_10! {
+                repeat_10! {
+                    total += name_of!(test_variable).len() + name_of!(test_field in TestStruct).len();
+                }
+            }
+        }
+
+        assert_eq!(total, 1000 * 23);
+        assert_eq!(test_variable, 1);
+    }
+
+    #[test]
+    fn name_of_forwarded_from_wrapper_macro() {
+        macro_rules! wrap_ident {
+            ($x: ident) => {
+                name_of!($x)
+            };
+        }
+
+        macro_rules! wrap_type {
+            ($t: ty) => {
+                name_of!(type $t)
+            };
+        }
+
+        macro_rules! wrap_tt {
+            ($($t: tt)+) => {
+                name_of!($($t)+)
+            };
+        }
+
+        let test_variable = 1;
+        assert_eq!(wrap_ident!(test_variable), "test_variable");
+        assert_eq!(wrap_ident!(test_fn), "test_fn");
+        assert_eq!(wrap_type!(Vec<TestStruct>), "Vec<TestStruct>");
+        assert_eq!(wrap_tt!(test_field in TestStruct), "test_field");
+        assert_eq!(wrap_tt!(const TEST_CONST in TestStruct), "TEST_CONST");
+    }
+
+    #[test]
+    fn name_of_boxed_trait_object_binding() {
+        let test_counter = std::cell::Cell::new(0);
+        let test_boxed: Box<dyn Fn() + '_> = Box::new(|| test_counter.set(test_counter.get() + 1));
+
+        assert_eq!(name_of!(test_boxed), "test_boxed");
+        assert_eq!(name_of!(ref test_boxed), "test_boxed");
+        assert_eq!(name_of!(fn test_boxed), "test_boxed");
+
+        test_boxed();
+        assert_eq!(test_counter.get(), 1);
+    }
+
+    #[test]
+    fn name_of_tuple_destructured_bindings() {
+        let (test_left, test_right) = (String::from("left"), vec![1, 2]);
+        assert_eq!(name_of!(test_left), "test_left");
+        assert_eq!(name_of!(test_right), "test_right");
+        assert_eq!(test_left, "left");
+        assert_eq!(test_right, [1, 2]);
+    }
+
+    #[test]
+    fn name_of_struct_destructured_bindings() {
+        let TestStruct { test_field } = TestStruct { test_field: 1 };
+        assert_eq!(name_of!(test_field), "test_field");
+
+        let TestStruct {
+            test_field: test_renamed,
+        } = TestStruct { test_field: 2 };
+        assert_eq!(name_of!(test_renamed), "test_renamed");
+        assert_eq!(test_field + test_renamed, 3);
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn name_of_unicode_binding() {
+        let λ = 1;
+        let café = 2;
+        let latteCafé = 3;
+
+        assert_eq!(name_of!(λ), "λ");
+        assert_eq!(name_of!(café), "caf\u{e9}");
+        assert_eq!(name_of!(café), "caf\u{e9}");
+        assert_eq!(name_of!(snake latteCafé), "latte_café");
+        assert_eq!(name_of!(screaming café), "CAFé");
+        assert_eq!(λ + café + latteCafé, 6);
+    }
+
+    #[test]
+    fn name_of_raw_binding() {
+        let r#type = 1;
+        assert_eq!(name_of!(r#type), "type");
+        assert_eq!(name_of!(ref r#type), "type");
+        assert_eq!(name_of!(mut r#type), "type");
+    }
+
+    #[test]
+    fn name_of_raw_struct_field() {
+        assert_eq!(name_of!(r#match in TestRawStruct), "match");
+        assert_eq!(
+            name_of!(r#type.test_field in TestRawStruct),
+            "type.test_field"
+        );
+    }
+
+    #[test]
+    fn name_of_fn_closure() {
+        let test_closure = |x: i32| x + 1;
+        assert_eq!(name_of!(test_closure), "test_closure");
+        assert_eq!(test_closure(1), 2);
+    }
+
+    #[test]
+    fn name_of_fn_mut_closure() {
+        let mut test_counter = 0;
+        let mut test_closure = || test_counter += 1;
+        assert_eq!(name_of!(test_closure), "test_closure");
+        test_closure();
+        assert_eq!(test_counter, 1);
+    }
+
+    #[test]
+    fn name_of_fn_once_closure() {
+        let test_variable = String::from("text");
+        let test_closure = move || test_variable;
+        assert_eq!(name_of!(test_closure), "test_closure");
+        assert_eq!(test_closure(), "text");
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn name_of_case_converted_binding() {
+        let camelCaseName = 1;
+        assert_eq!(name_of!(snake camelCaseName), "camel_case_name");
+        assert_eq!(name_of!(kebab camelCaseName), "camel-case-name");
+        assert_eq!(name_of!(screaming camelCaseName), "CAMEL_CASE_NAME");
+    }
+
+    #[test]
+    fn name_of_case_converted_unit_struct() {
+        struct SomeType;
+
+        assert_eq!(name_of!(snake SomeType), "some_type");
+        assert_eq!(name_of!(kebab SomeType), "some-type");
+        assert_eq!(name_of!(screaming SomeType), "SOME_TYPE");
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn name_of_case_converted_acronyms_and_digits() {
+        let HTTPServer = 1;
+        let test_fn_v2Name = 2;
+        let r#type = 3;
+        assert_eq!(name_of!(snake HTTPServer), "http_server");
+        assert_eq!(name_of!(kebab test_fn_v2Name), "test-fn-v2-name");
+        assert_eq!(name_of!(screaming test_fn), "TEST_FN");
+        assert_eq!(name_of!(screaming r#type), "TYPE");
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn name_of_case_converted_leading_underscores() {
+        let _private = 1;
+        let __privateValue = 2;
+        assert_eq!(name_of!(snake _private), "_private");
+        assert_eq!(name_of!(kebab _private), "_private");
+        assert_eq!(name_of!(screaming _private), "_PRIVATE");
+        assert_eq!(name_of!(snake __privateValue), "__private_value");
+        assert_eq!(name_of!(kebab __privateValue), "__private-value");
+        assert_eq!(name_of!(screaming __privateValue), "__PRIVATE_VALUE");
+    }
+
+    #[test]
+    fn name_of_fn() {
+        assert_eq!(name_of!(test_fn), "test_fn");
+    }
+
+    #[test]
+    fn name_of_ref_binding() {
+        let test_variable = 123;
+        assert_eq!(name_of!(ref test_variable), "test_variable");
+    }
+
+    #[test]
+    fn name_of_mut_binding() {
+        let mut test_variable = 123;
+        let test_borrow = &mut test_variable;
+        assert_eq!(name_of!(mut test_variable), "test_variable");
+        *test_borrow += 1;
+        assert_eq!(test_variable, 124);
+    }
+
+    #[test]
+    fn name_of_static_mut() {
+        assert_eq!(name_of!(TEST_STATIC_MUT), "TEST_STATIC_MUT");
+    }
+
+    #[test]
+    fn name_of_fn_keyword() {
+        assert_eq!(name_of!(fn test_fn), "test_fn");
+        assert_eq!(name_of!(fn test_fn_with_args), "test_fn_with_args");
+    }
+
+    #[test]
+    fn name_of_fn_keyword_with_same_named_type() {
+        use self::test_shadow::test_fn;
+
+        let _: Option<test_fn> = None;
+        assert_eq!(name_of!(fn test_fn), "test_fn");
+    }
+
+    #[test]
+    fn name_of_fn_keyword_closure() {
+        let test_closure = |x: i32| x + 1;
+        assert_eq!(name_of!(fn test_closure), "test_closure");
+    }
+
+    #[test]
+    fn name_of_fn_pointer_binding() {
+        let test_fn_pointer: fn() = test_fn;
+        let test_fn_pointer_with_args = test_fn_with_args as fn(i32, &str) -> bool;
+        assert_eq!(name_of!(test_fn_pointer), "test_fn_pointer");
+        assert_eq!(name_of!(ref test_fn_pointer), "test_fn_pointer");
+        assert_eq!(name_of!(fn test_fn_pointer), "test_fn_pointer");
+        assert_eq!(
+            name_of!(fn test_fn_pointer_with_args),
+            "test_fn_pointer_with_args"
+        );
+        test_fn_pointer();
+        assert!(test_fn_pointer_with_args(1, "text"));
+    }
+
+    #[test]
+    fn name_of_fn_through_pointer() {
+        let test_fn_pointer: fn(i32, &str) -> bool = test_fn_with_args;
+        assert_eq!(name_of!(fn test_fn_with_args), "test_fn_with_args");
+        assert!(test_fn_pointer(1, "text"));
+        assert_eq!(
+            name_of_type!(fn(i32, &str) -> bool),
+            "fn(i32, &str) -> bool"
+        );
+    }
+
+    #[test]
+    fn name_of_method() {
+        assert_eq!(name_of!(fn new in Vec<u8>), "new");
+        assert_eq!(name_of!(fn self_name in TestStruct), "self_name");
+    }
+
+    #[test]
+    fn name_of_trait_method() {
+        assert_eq!(name_of!(fn clone in String), "clone");
+        assert_eq!(
+            name_of!(fn trait_self_name in TestStruct),
+            "trait_self_name"
+        );
+    }
+
+    #[test]
+    fn name_of_static_keyword() {
+        assert_eq!(name_of!(static TEST_STATIC), "TEST_STATIC");
+        assert_eq!(name_of!(static TEST_STATIC_MUT), "TEST_STATIC_MUT");
+    }
+
+    #[test]
+    fn name_of_linked_static() {
+        assert_eq!(name_of!(TEST_LINKED_STATIC), "TEST_LINKED_STATIC");
+        assert_eq!(name_of!(static TEST_LINKED_STATIC), "TEST_LINKED_STATIC");
+        assert_eq!(
+            name_of!(static TEST_LINKED_STATIC_MUT),
+            "TEST_LINKED_STATIC_MUT"
+        );
+        assert_eq!(name_of!(TEST_LINKED_STATIC_MUT), "TEST_LINKED_STATIC_MUT");
+    }
+
+    #[test]
+    fn name_of_generic_fn() {
+        assert_eq!(name_of!(test_generic_fn::<u8>), "test_generic_fn");
+        assert_eq!(name_of!(test_generic_fn::<Vec<String>>), "test_generic_fn");
+    }
+
+    #[test]
+    fn name_of_generic_method() {
+        assert_eq!(
+            name_of!(fn collect::<Vec<u8>> in std::vec::IntoIter<u8>),
+            "collect"
+        );
+        assert_eq!(
+            name_of!(fn test_generic_assoc_fn::<u8> in TestStruct),
+            "test_generic_assoc_fn"
+        );
+    }
+
+    #[test]
+    fn name_of_generic_method_on_concrete_type() {
+        assert_eq!(name_of!(fn extend::<Vec<u8>> in Vec<u8>), "extend");
+        assert_eq!(name_of!(fn collect::<String> in std::str::Chars), "collect");
+        assert_eq!(
+            name_of!(fn map::<u16, fn(u8) -> u16> in std::vec::IntoIter<u8>),
+            "map"
+        );
+    }
+
+    #[test]
+    fn name_of_method_path() {
+        assert_eq!(name_of!(String::push), "push");
+        assert_eq!(name_of!(TestStruct::self_name), "self_name");
+    }
+
+    #[test]
+    fn name_of_trait_method_path() {
+        assert_eq!(name_of!(String::clone), "clone");
+        assert_eq!(name_of!(TestStruct::trait_self_name), "trait_self_name");
+    }
+
+    #[test]
+    fn name_of_function_path() {
+        assert_eq!(name_of!(std::process::exit), "exit");
+        assert_eq!(name_of!(self::test_fn), "test_fn");
+    }
+
+    #[test]
+    fn name_of_lifetime() {
+        fn lifetime_name<'a>(_: &'a str) -> &'static str {
+            name_of!(lifetime 'a)
+        }
+
+        #[allow(dead_code)]
+        struct TestLifetimeStruct<'a>(&'a str);
+
+        impl<'a> TestLifetimeStruct<'a> {
+            fn lifetime_name(&self) -> &'static str {
+                name_of!(lifetime 'a)
+            }
+        }
+
+        assert_eq!(lifetime_name("test"), "'a");
+        assert_eq!(TestLifetimeStruct("test").lifetime_name(), "'a");
+        assert_eq!(name_of!(lifetime 'static), "'static");
+    }
+
+    #[test]
+    fn name_of_macro() {
+        assert_eq!(name_of!(macro println), "println");
+        assert_eq!(name_of!(macro name_of!), "name_of");
+    }
+
+    #[test]
+    fn name_of_type() {
+        assert_eq!(name_of!(type i32), "i32");
+        assert_eq!(name_of_type!(i32), "i32");
+    }
+
+    #[test]
+    fn name_of_reference_type() {
+        assert_eq!(name_of!(type &str), "&str");
+        assert_eq!(name_of_type!(&str), "&str");
+        assert_eq!(name_of_type!(&mut TestStruct), "&mut TestStruct");
+        assert_eq!(name_of_type!(&[u8]), "&[u8]");
+    }
+
+    #[test]
+    fn name_of_reference_type_with_lifetime() {
+        fn type_names<'a, T>(_: &'a mut T) -> [&'static str; 3] {
+            [
+                name_of_type!(&'a mut T),
+                name_of_type!(Vec<&'a str>),
+                name_of!(type Box<dyn TestGenericTrait<T> + 'a>),
+            ]
+        }
+
+        assert_eq!(name_of_type!(&'static str), "&'static str");
+        assert_eq!(name_of!(type &'static [u8]), "&'static [u8]");
+        assert_eq!(
+            type_names(&mut 1),
+            [
+                "&'a mut T",
+                "Vec<&'a str>",
+                "Box<dyn TestGenericTrait<T> + 'a>"
+            ]
+        );
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn name_of_tuple_type() {
+        assert_eq!(name_of_type!(()), "()");
+        assert_eq!(name_of!(type (i32, String)), "(i32, String)");
+        assert_eq!(name_of_type!((u8,)), "(u8,)");
+        assert_eq!(name_of_type!(((u8, TestStruct), ())), "((u8, TestStruct), ())");
+        assert_eq!(name_of_type!(( i32 , Vec < ( u8 , u16 ) > )), "(i32, Vec<(u8, u16)>)");
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn name_of_fn_pointer_type() {
+        assert_eq!(name_of_type!(fn(i32) -> i32), "fn(i32) -> i32");
+        assert_eq!(name_of!(type fn()), "fn()");
+        assert_eq!(name_of_type!(fn ( i32 , &str )->bool), "fn(i32, &str) -> bool");
+        assert_eq!(name_of_type!(extern "C" fn()), "extern \"C\" fn()");
+        assert_eq!(
+            name_of_type!(unsafe extern "C" fn(*const u8) -> i32),
+            "unsafe extern \"C\" fn(*const u8) -> i32"
+        );
+        assert_eq!(name_of_type!(for<'a> fn(&'a str) -> &'a str), "for<'a> fn(&'a str) -> &'a str");
+        assert_eq!(name_of_type!(Box<dyn Fn(u8) -> u8>), "Box<dyn Fn(u8) -> u8>");
+    }
+
+    #[test]
+    fn name_of_pointer_type() {
+        assert_eq!(name_of!(type *const u8), "*const u8");
+        assert_eq!(name_of_type!(*mut TestStruct), "*mut TestStruct");
+    }
+
+    #[test]
+    fn name_of_array_type() {
+        assert_eq!(name_of!(type [u8; 4]), "[u8; 4]");
+        assert_eq!(name_of_type!([TestStruct; 2]), "[TestStruct; 2]");
+    }
+
+    #[test]
+    fn name_of_const_generic_type() {
+        assert_eq!(
+            name_of_type!(TestConstGenericStruct<3, 4>),
+            "TestConstGenericStruct<3, 4>"
+        );
+        assert_eq!(
+            name_of!(type TestConstGenericStruct<{ 1 + 2 }, 4>),
+            "TestConstGenericStruct<{ 1 + 2 }, 4>"
+        );
+        assert_eq!(
+            name_of!(test_field in TestConstGenericStruct<3, 4>),
+            "test_field"
+        );
+    }
+
+    #[test]
+    fn name_of_array_type_with_const_expression() {
+        const TEST_LEN: usize = 4;
+
+        fn type_name<const N: usize>() -> &'static str {
+            name_of_type!([u8; N])
+        }
+
+        assert_eq!(name_of_type!([u8; TEST_LEN]), "[u8; TEST_LEN]");
+        assert_eq!(name_of_type!([u8; 2 * TEST_LEN]), "[u8; 2 * TEST_LEN]");
+        assert_eq!(type_name::<4>(), "[u8; N]");
+    }
+
+    #[test]
+    fn name_of_array_type_with_named_length() {
+        assert_eq!(name_of!(type [u8; 16]), "[u8; 16]");
+        assert_eq!(name_of!(type [u8; TEST_ARRAY_LEN]), "[u8; TEST_ARRAY_LEN]");
+        assert_eq!(
+            name_of_type!([[u8; TEST_ARRAY_LEN]; 2]),
+            "[[u8; TEST_ARRAY_LEN]; 2]"
+        );
+        assert_eq!(
+            name_of_type!([u8; TestStruct::TEST_ARRAY_LEN]),
+            "[u8; TestStruct::TEST_ARRAY_LEN]"
+        );
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn name_of_qualified_associated_type() {
+        assert_eq!(
+            name_of!(type <Vec<u8> as IntoIterator>::IntoIter),
+            "<Vec<u8> as IntoIterator>::IntoIter"
+        );
+        assert_eq!(
+            name_of_type!(<TestStruct as TestAssociatedTypeTrait>::TestAssociatedType),
+            "<TestStruct as TestAssociatedTypeTrait>::TestAssociatedType"
+        );
+        assert_eq!(
+            name_of_type!(< Vec < u8 > as IntoIterator > :: Item),
+            "<Vec<u8> as IntoIterator>::Item"
+        );
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn name_of_operator_output_type() {
+        assert_eq!(
+            name_of_type!(<i32 as core::ops::Add>::Output),
+            "<i32 as core::ops::Add>::Output"
+        );
+        assert_eq!(
+            name_of!(type <u8 as std::ops::Add<&u8>>::Output),
+            "<u8 as std::ops::Add<&u8>>::Output"
+        );
+        assert_eq!(
+            name_of_type!(<String as std::ops::Deref>::Target),
+            "<String as std::ops::Deref>::Target"
+        );
+        assert_eq!(
+            name_of_type!(&<Vec<u8> as std::ops::Deref>::Target),
+            "&<Vec<u8> as std::ops::Deref>::Target"
+        );
+        assert_eq!(name_of!(type Output in std::ops::Add), "Output");
+        assert_eq!(name_of!(type Target in std::ops::Deref), "Target");
+    }
+
+    #[test]
+    fn name_of_trait_object_type() {
+        assert_eq!(
+            name_of!(type dyn Iterator<Item = u8>),
+            "dyn Iterator<Item = u8>"
+        );
+        assert_eq!(
+            name_of_type!(dyn TestGenericTrait<i32>),
+            "dyn TestGenericTrait<i32>"
+        );
+        assert_eq!(name_of_type!(dyn std::fmt::Debug), "dyn std::fmt::Debug");
+    }
+
+    type TestAlias = u64;
+    type TestGenericAlias<T> = Option<Vec<T>>;
+
+    #[test]
+    fn name_of_type_alias() {
+        assert_eq!(name_of_type!(TestAlias), "TestAlias");
+        assert_eq!(name_of!(type TestGenericAlias<u8>), "TestGenericAlias<u8>");
+    }
+
+    #[test]
+    fn name_of_resolved_type_alias() {
+        assert_eq!(name_of_type!(resolved TestAlias), "u64");
+        assert_eq!(
+            name_of_type!(resolved TestGenericAlias<u8>),
+            "core::option::Option<alloc::vec::Vec<u8>>"
+        );
+        assert_eq!(name_of_type!(resolved str), "str");
+    }
+
+    #[test]
+    fn name_of_multi_bound_trait_object_type() {
+        assert_eq!(
+            name_of_type!(dyn std::fmt::Debug + Send),
+            "dyn std::fmt::Debug + Send"
+        );
+        assert_eq!(
+            name_of!(type dyn Iterator<Item = u8> + Send + Sync),
+            "dyn Iterator<Item = u8> + Send + Sync"
+        );
+    }
+
+    #[test]
+    fn name_of_boxed_multi_bound_trait_object_type() {
+        assert_eq!(
+            name_of_type!(Box<dyn core::fmt::Debug + Send + Sync>),
+            "Box<dyn core::fmt::Debug + Send + Sync>"
+        );
+        assert_eq!(
+            name_of!(type Box<dyn std::error::Error + Send + Sync + 'static>),
+            "Box<dyn std::error::Error + Send + Sync + 'static>"
+        );
+        assert_eq!(
+            name_of_type!(Box<dyn Iterator<Item=u8>+Send>),
+            "Box<dyn Iterator<Item = u8> + Send>"
+        );
+        assert_eq!(
+            name_of_type!(&'static (dyn Fn(u8) -> u8 + Send + Sync)),
+            "&'static (dyn Fn(u8) -> u8 + Send + Sync)"
+        );
+    }
+
+    #[test]
+    fn name_of_impl_trait_type() {
+        assert_eq!(name_of_type!(impl Iterator), "impl Iterator");
+        assert_eq!(
+            name_of!(type impl TestGenericTrait<i32> + Send),
+            "impl TestGenericTrait<i32> + Send"
+        );
+    }
+
+    #[test]
+    fn name_of_impl_trait_type_with_associated_type() {
+        assert_eq!(
+            name_of_type!(impl Iterator<Item = u8>),
+            "impl Iterator<Item = u8>"
+        );
+        assert_eq!(
+            name_of!(type impl Iterator<Item=Vec<u8>>),
+            "impl Iterator<Item = Vec<u8>>"
+        );
+    }
+
+    #[test]
+    fn name_of_impl_trait_type_in_generic_fn() {
+        fn name<T>() -> &'static str {
+            name_of_type!(impl Into<T> + Clone)
+        }
+
+        fn name_with_item<'a, T: 'a>() -> &'static str {
+            name_of_type!(impl Iterator<Item = &'a T> + 'a)
+        }
+
+        assert_eq!(name::<u8>(), "impl Into<T> + Clone");
+        assert_eq!(name_with_item::<u8>(), "impl Iterator<Item = &'a T> + 'a");
+    }
+
+    #[test]
+    fn name_of_struct() {
+        assert_eq!(name_of!(type TestStruct), "TestStruct");
+        assert_eq!(name_of_type!(TestStruct), "TestStruct");
+    }
+
+    #[test]
+    fn name_of_in_const() {
+        assert_eq!(TEST_CONST_FN_NAME, "test_fn");
+        assert_eq!(TEST_CONST_TYPE_NAME, "TestStruct");
+        assert_eq!(TEST_CONST_FIELD_NAME, "test_field");
+        assert_eq!(TEST_CONST_CONST_NAME, "TEST_CONST");
+        assert_eq!(TEST_CONST_STATIC_MUT_NAME, "TEST_STATIC_MUT");
+        assert_eq!(TEST_CONST_TRAIT_NAME, "TestTrait");
+        assert_eq!(TEST_CONST_MODULE_NAME, "test_nested_module");
+    }
+
+    #[test]
+    fn const_field_name_in_const() {
+        assert_eq!(TEST_CONST_FIELD_NAME_MACRO, "test_field");
+        assert_eq!(TEST_CONST_TUPLE_FIELD_NAME, "1");
+        assert_eq!(TEST_CONST_RAW_FIELD_NAME, "type");
+        assert_eq!(TEST_CONST_NESTED_FIELD_NAME, "type.test_field");
+        assert_eq!(const_field_name!(0 in TestTupleStruct), "0");
+    }
+
+    #[test]
+    fn const_field_name_with_length_named_like_helper() {
+        const NAME: usize = 2;
+        assert_eq!(const_field_name!(1 in ([u8; NAME], u8)), "1");
+        assert_eq!(const_field_name!(0.1 in (([u8; NAME], u8),)), "0.1");
+    }
+
+    #[test]
+    fn name_of_in_static() {
+        assert_eq!(TEST_STATIC_FN_NAME, "test_fn");
+        assert_eq!(TEST_STATIC_TYPE_NAME, "TestStruct");
+    }
+
+    #[test]
+    fn name_of_self() {
+        assert_eq!(TestStruct::self_name(), "Self");
+        assert_eq!(TestStruct::trait_self_name(), "Self");
+    }
+
+    #[test]
+    fn name_of_generic_struct() {
+        assert_eq!(
+            name_of!(type TestGenericStruct<i32>),
+            "TestGenericStruct<i32>"
+        );
+
+        assert_eq!(
+            name_of_type!(TestGenericStruct<i32>),
+            "TestGenericStruct<i32>"
+        );
+    }
+
+    #[test]
+    fn name_of_generic_multi_type_struct() {
+        assert_eq!(
+            name_of!(type TestGenericStructMultiType<i32, TestGenericStruct<String>>),
+            "TestGenericStructMultiType<i32, TestGenericStruct<String>>"
+        );
+
+        assert_eq!(
+            name_of_type!( TestGenericStructMultiType<i32, TestGenericStruct<String>>),
+            "TestGenericStructMultiType<i32, TestGenericStruct<String>>"
+        );
+    }
+
+    #[test]
+    fn name_of_const_param_in_generic_fn() {
+        assert_eq!(test_const_param_names::<4, true, 'c'>(), ["N", "FLAG", "C"]);
+    }
+
+    #[test]
+    fn name_of_const_param_in_generic_impl() {
+        assert_eq!(
+            TestConstGenericStruct::<2, 3>::const_param_names(),
+            ["R", "C"]
+        );
+    }
+
+    #[test]
+    fn name_of_type_param_in_generic_fn() {
+        assert_eq!(test_type_param_names::<u8, str, ()>(), ["T", "U", "V"]);
+    }
+
+    #[test]
+    fn name_of_type_param_in_generic_impl() {
+        assert_eq!(
+            TestGenericStructMultiType::<u8, u8>::type_param_names(),
+            ["T"]
+        );
+    }
+
+    #[test]
+    fn name_of_trait() {
+        assert_eq!(name_of!(trait Iterator), "Iterator");
+        assert_eq!(name_of!(trait TestTrait), "TestTrait");
+    }
+
+    #[test]
+    fn name_of_generic_trait() {
+        assert_eq!(name_of!(trait From<u8>), "From<u8>");
+        assert_eq!(
+            name_of!(trait TestGenericTrait<i32>),
+            "TestGenericTrait<i32>"
+        );
+    }
+
+    #[test]
+    fn name_of_generic_trait_in_generic_fn() {
+        fn trait_names<T>() -> [&'static str; 2] {
+            [name_of!(trait From<T>), name_of!(trait Iterator<Item = T>)]
+        }
+
+        assert_eq!(trait_names::<u8>(), ["From<T>", "Iterator<Item = T>"]);
+    }
+
+    #[test]
+    fn name_of_trait_by_path() {
+        assert_eq!(name_of!(trait ::core::fmt::Debug), "::core::fmt::Debug");
+        assert_eq!(name_of!(trait Fn(u8) -> u8), "Fn(u8) -> u8");
+    }
+
+    #[test]
+    fn name_of_module() {
+        assert_eq!(name_of!(mod self::test_module), "test_module");
+        assert_eq!(name_of!(mod ::std::collections), "collections");
+    }
+
+    #[test]
+    fn name_of_nested_module() {
+        assert_eq!(
+            name_of!(mod self::test_module::test_nested_module),
+            "test_nested_module"
+        );
+    }
+
+    #[test]
+    fn full_name_of_binding() {
+        let test_variable = 123;
+        assert_eq!(
+            full_name_of!(test_variable),
+            concat!(module_path!(), "::test_variable")
+        );
+    }
+
+    #[test]
+    fn full_name_of_fn() {
+        assert_eq!(full_name_of!(test_fn), concat!(module_path!(), "::test_fn"));
+        assert!(full_name_of!(test_fn).starts_with("nameof::tests::"));
+    }
+
+    #[test]
+    fn full_name_of_type() {
+        assert_eq!(
+            full_name_of!(type TestStruct),
+            concat!(module_path!(), "::TestStruct")
+        );
+        assert_eq!(
+            full_name_of!(type TestGenericStruct<i32>),
+            concat!(module_path!(), "::TestGenericStruct<i32>")
+        );
+    }
+
+    #[test]
+    fn module_path_of_current_module() {
+        assert_eq!(module_path_of!(), module_path!());
+        assert_eq!(module_path_of!(), "nameof::tests");
+    }
+
+    #[test]
+    fn module_path_of_item() {
+        let test_variable = 1;
+        assert_eq!(
+            module_path_of!(test_fn),
+            concat!(module_path!(), "::test_fn")
+        );
+        assert_eq!(
+            module_path_of!(test_variable),
+            concat!(module_path!(), "::test_variable")
+        );
+        assert!(module_path_of!(type TestStruct).starts_with(module_path_of!()));
+    }
+
+    #[test]
+    fn names_of_bindings() {
+        let test_variable_x = 1;
+        let test_variable_y = 2;
+        assert_eq!(
+            names_of!(test_variable_x, test_variable_y),
+            ["test_variable_x", "test_variable_y"]
+        );
+    }
+
+    #[test]
+    fn names_of_single_binding() {
+        let test_variable = 1;
+        let names: [&str; 1] = names_of!(test_variable,);
+        assert_eq!(names, ["test_variable"]);
+    }
+
+    #[test]
+    fn names_of_mixed_bindings_and_fns() {
+        let test_variable = 1;
+        assert_eq!(
+            names_of!(test_variable, test_fn),
+            ["test_variable", "test_fn"]
+        );
+    }
+
+    #[test]
+    fn name_of_type_in_generic_fn() {
+        fn type_name<T>() -> &'static str {
+            name_of_type!(T)
+        }
+
+        assert_eq!(type_name::<i32>(), "T");
+    }
+
+    #[test]
+    fn name_of_type_with_inferred_generic_args() {
+        assert_eq!(name_of_type!(Vec<_>), "Vec<_>");
+        assert_eq!(name_of_type!(HashMap<_, _>), "HashMap<_, _>");
+        assert_eq!(name_of!(type HashMap<_, u32>), "HashMap<_, u32>");
+        assert_eq!(name_of_type!(std::vec::Vec<_>), "std::vec::Vec<_>");
+        assert_eq!(
+            name_of_type!(std::borrow::Cow<'static, _>),
+            "std::borrow::Cow<'static, _>"
+        );
+        assert_eq!(name_of_type!(::std::vec::Vec<_>), "Vec<_>");
+    }
+
+    #[test]
+    fn name_of_type_with_trailing_lifetime_or_comma() {
+        #[allow(dead_code)]
+        struct TestLifetimeStruct<'a>(&'a str);
+
+        assert_eq!(
+            name_of_type!(TestLifetimeStruct<'static>),
+            "TestLifetimeStruct<'static>"
+        );
+        assert_eq!(
+            name_of_type!(std::fmt::Formatter<'_>),
+            "std::fmt::Formatter<'_>"
+        );
+        assert_eq!(name_of_type!(Vec<u8,>), "Vec<u8,>");
+        assert_eq!(name_of_type!(HashMap<_, u32,>), "HashMap<_, u32,>");
+    }
+
+    #[test]
+    fn short_name_of_type_with_length_named_like_helper() {
+        const LEN: usize = 4;
+        const NAME: usize = 2;
+        assert_eq!(short_name_of_type!([u8; LEN]), "[u8; LEN]");
+        assert_eq!(short_name_of_type!([Vec<u8>; NAME]), "[Vec<u8>; NAME]");
+    }
+
+    #[test]
+    fn short_name_of_type() {
+        assert_eq!(
+            short_name_of_type!(TestGenericStruct<i32>),
+            "TestGenericStruct"
+        );
+        assert_eq!(short_name_of_type!(HashMap<String, u32>), "HashMap");
+        assert_eq!(short_name_of_type!(Vec<_>), "Vec");
+        assert_eq!(short_name_of_type!(Vec::<u8>), "Vec");
+        assert_eq!(short_name_of_type!(std::vec::Vec<u8>), "std::vec::Vec");
+        assert_eq!(short_name_of_type!(::std::vec::Vec<u8>), "Vec");
+    }
+
+    #[test]
+    fn short_name_of_type_unchanged() {
+        assert_eq!(short_name_of_type!(TestStruct), "TestStruct");
+        assert_eq!(short_name_of_type!(i32), "i32");
+        assert_eq!(short_name_of_type!(&[u8]), "&[u8]");
+        assert_eq!(short_name_of_type!(&Vec<u8>), "&Vec<u8>");
+        assert_eq!(
+            short_name_of_type!(<Vec<u8> as IntoIterator>::IntoIter),
+            "<Vec<u8> as IntoIterator>::IntoIter"
+        );
+    }
+
+    #[test]
+    fn short_name_of_type_const() {
+        const NAME: &str = short_name_of_type!(HashMap<String, u32>);
+        assert_eq!(NAME, "HashMap");
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn name_of_type_normalized() {
+        assert_eq!(name_of_type!(Vec < HashMap < String , u32 > >), "Vec<HashMap<String, u32>>");
+        assert_eq!(name_of_type!(Vec<HashMap<String,Vec< u32 >>>), "Vec<HashMap<String, Vec<u32>>>");
+        assert_eq!(
+            name_of!(type TestGenericStructMultiType < i32 ,TestGenericStruct < String > >),
+            "TestGenericStructMultiType<i32, TestGenericStruct<String>>"
+        );
+        assert_eq!(name_of_type!(& [ u8 ]), "&[u8]");
+        assert_eq!(name_of_type!(& mut TestStruct), "&mut TestStruct");
+        assert_eq!(name_of_type!([u8;4]), "[u8; 4]");
+        assert_eq!(name_of_type!([u8;2*4]), "[u8; 2 * 4]");
+        assert_eq!(name_of_type!([*const u8;8/2]), "[*const u8; 8 / 2]");
+        assert_eq!(name_of_type!(&'static *const u8), "&'static *const u8");
+        assert_eq!(name_of_type!(TestConstGenericStruct<{1+2},4>), "TestConstGenericStruct<{ 1 + 2 }, 4>");
+        assert_eq!(name_of_type!(std :: vec :: Vec < u8 >), "std::vec::Vec<u8>");
+    }
+
+    #[test]
+    fn normalize_type_nested_generics() {
+        assert_eq!(
+            __normalize_type!("Vec < HashMap < String , Vec < u32 > > >"),
+            "Vec<HashMap<String, Vec<u32>>>"
+        );
+        assert_eq!(
+            __normalize_type!("Option<Box<dyn Fn(u8)->u8>>"),
+            "Option<Box<dyn Fn(u8) -> u8>>"
+        );
+        assert_eq!(
+            __normalize_type!("std :: vec :: Vec<u8>"),
+            "std::vec::Vec<u8>"
+        );
+    }
+
+    #[test]
+    fn normalize_type_references() {
+        assert_eq!(__normalize_type!("& str"), "&str");
+        assert_eq!(__normalize_type!("& 'static str"), "&'static str");
+        assert_eq!(__normalize_type!("& mut Vec < u8 >"), "&mut Vec<u8>");
+        assert_eq!(__normalize_type!("& & mut u8"), "&&mut u8");
+    }
+
+    #[test]
+    fn normalize_type_slices_and_arrays() {
+        assert_eq!(__normalize_type!("& [ u8 ]"), "&[u8]");
+        assert_eq!(__normalize_type!("[ u8 ; 4 ]"), "[u8; 4]");
+        assert_eq!(__normalize_type!("& [ ( u8 , u16 ) ]"), "&[(u8, u16)]");
+    }
+
+    #[test]
+    fn normalize_type_len() {
+        assert_eq!(super::__normalized_type_len("Vec < u8 >"), "Vec<u8>".len());
+        assert_eq!(super::__normalize_type::<7>("Vec < u8 >"), *b"Vec<u8>");
+    }
+
+    #[test]
+    fn enum_variants_with_values_of_enum() {
+        assert_eq!(
+            enum_variants_with_values_of!(TestEnum {
+                Red,
+                Green,
+                Blue,
+                Rgb(u8, u8, u8),
+                Hsl { h, s, l },
+            }),
+            ["Red", "Green", "Blue", "Rgb(u8, u8, u8)", "Hsl { h, s, l }"]
+        );
+    }
+
+    #[test]
+    fn enum_variants_with_values_of_complex_types() {
+        assert_eq!(
+            enum_variants_with_values_of!(TestNonExhaustiveEnum {
+                Unit,
+                Tuple(u8, u8),
+                Struct { test_field }
+            }),
+            ["Unit", "Tuple(u8, u8)", "Struct { test_field }"]
+        );
+        assert_eq!(
+            enum_variants_with_values_of!(TestComplexEnum {
+                Empty,
+                Boxed(Box<dyn Fn(u8) -> u8>, Vec<Option<&'static str>>),
+                Raw { r#type }
+            }),
+            [
+                "Empty",
+                "Boxed(Box<dyn Fn(u8) -> u8>, Vec<Option<&'static str>>)",
+                "Raw { type }"
+            ]
+        );
+    }
+
+    #[test]
+    fn discriminant_name_of_value() {
+        let test_enum = TestEnum::Rgb(1, 2, 3);
+        assert_eq!(
+            discriminant_name_of!(test_enum in TestEnum { Red, Green, Blue, Rgb, Hsl }),
+            "Rgb"
+        );
+        assert!(matches!(test_enum, TestEnum::Rgb(1, 2, 3)));
+    }
+
+    #[test]
+    fn discriminant_name_of_runtime_values() {
+        let test_values = [
+            TestEnum::Green,
+            TestEnum::Hsl { h: 1, s: 2, l: 3 },
+            TestEnum::Red,
+        ];
+        let test_names: Vec<&str> = test_values
+            .iter()
+            .map(|test_value| {
+                discriminant_name_of!(test_value in TestEnum { Red, Green, Blue, Rgb, Hsl })
+            })
+            .collect();
+        assert_eq!(test_names, ["Green", "Hsl", "Red"]);
+        assert_eq!(
+            discriminant_name_of!((test_values[1]) in TestEnum { Red, Green, Blue, Rgb, Hsl }),
+            "Hsl"
+        );
+    }
+
+    #[test]
+    fn variant_index_of_enum() {
+        assert_eq!(
+            variant_index_of!(Red in TestEnum { Red, Green, Blue, Rgb, Hsl }),
+            0
+        );
+        assert_eq!(
+            variant_index_of!(Blue in TestEnum { Red, Green, Blue, Rgb, Hsl }),
+            2
+        );
+        assert_eq!(
+            variant_index_of!(Hsl in TestEnum { Red, Green, Blue, Rgb, Hsl, }),
+            4
+        );
+        assert!(
+            variant_index_of!(Green in TestEnum { Red, Green, Blue, Rgb, Hsl })
+                < variant_index_of!(Rgb in TestEnum { Red, Green, Blue, Rgb, Hsl })
+        );
+    }
+
+    #[test]
+    fn variant_index_of_enum_in_list_order() {
+        assert_eq!(
+            variant_index_of!(Blue in TestEnum { Blue, Red, Green, Hsl, Rgb }),
+            0
+        );
+        assert_eq!(
+            variant_index_of!(Rgb in TestEnum { Blue, Red, Green, Hsl, Rgb }),
+            4
+        );
+    }
+
+    #[test]
+    fn variant_count_of_enum() {
+        assert_eq!(
+            variant_count_of!(TestEnum {
+                Red,
+                Green,
+                Blue,
+                Rgb,
+                Hsl,
+            }),
+            5
+        );
+    }
+
+    #[test]
+    fn variant_count_of_enum_in_any_order() {
+        assert_eq!(
+            variant_count_of!(TestEnum {
+                Hsl,
+                Rgb,
+                Blue,
+                Green,
+                Red
+            }),
+            5
+        );
+    }
+
+    #[test]
+    fn variants_of_enum() {
+        assert_eq!(
+            variants_of!(TestEnum {
+                Red,
+                Green,
+                Blue,
+                Rgb,
+                Hsl
+            }),
+            ["Red", "Green", "Blue", "Rgb", "Hsl"]
+        );
+    }
+
+    #[test]
+    fn field_names_of_struct() {
+        assert_eq!(field_names_of!(TestStruct { test_field }), ["test_field"]);
+        assert_eq!(
+            field_names_of!(TestRawStruct { r#type, r#match }),
+            ["type", "match"]
+        );
+    }
+
+    #[test]
+    fn field_names_of_generic_struct() {
+        assert_eq!(
+            field_names_of!(TestGenericStructMultiType<i32, String> {
+                test_field_t,
+                test_field_u,
+            }),
+            ["test_field_t", "test_field_u"]
+        );
+    }
+
+    #[test]
+    fn path_segments_of_path() {
+        assert_eq!(
+            path_segments_of!(std::collections::HashMap),
+            ["std", "collections", "HashMap"]
+        );
+        assert_eq!(path_segments_of!(std::mem::swap), ["std", "mem", "swap"]);
+    }
+
+    #[test]
+    fn path_segments_of_absolute_path() {
+        assert_eq!(path_segments_of!(::std::vec::Vec), ["std", "vec", "Vec"]);
+        assert_eq!(
+            path_segments_of!(::std::process::abort),
+            ["std", "process", "abort"]
+        );
+    }
+
+    #[test]
+    fn name_of_absolute_type_path() {
+        assert_eq!(name_of!(type ::std::vec::Vec<u8>), "Vec<u8>");
+        assert_eq!(name_of_type!(::std::vec::Vec<u8>), "Vec<u8>");
+        assert_eq!(
+            name_of_type!(::std::collections::HashMap<::std::string::String, u32>),
+            "HashMap<::std::string::String, u32>"
+        );
+        assert_eq!(name_of_type!(::std::vec::Vec::<u8>), "Vec::<u8>");
+        assert_eq!(name_of_type!(::core::primitive::u8), "u8");
+    }
+
+    #[test]
+    fn name_of_absolute_fn_path() {
+        assert_eq!(name_of!(::std::process::abort), "abort");
+        assert_eq!(name_of!(::core::u8::MAX), "MAX");
+    }
+
+    #[test]
+    fn full_name_of_absolute_type_path() {
+        assert_eq!(full_name_of!(type ::std::vec::Vec<u8>), "std::vec::Vec<u8>");
+    }
+
+    #[test]
+    fn path_segments_of_relative_path() {
+        assert_eq!(
+            path_segments_of!(crate::tests::TestStruct),
+            ["crate", "tests", "TestStruct"]
+        );
+        assert_eq!(
+            path_segments_of!(self::test_module::test_nested_module),
+            ["self", "test_module", "test_nested_module"]
+        );
+        assert_eq!(
+            path_segments_of!(super::tests::test_fn),
+            ["super", "tests", "test_fn"]
+        );
+    }
+
+    #[test]
+    fn name_len_of_binding() {
+        let test_variable = 1;
+        assert_eq!(name_len_of!(test_variable), 13);
+        assert_eq!(name_len_of!(type TestStruct), 10);
+    }
+
+    #[test]
+    fn name_len_of_as_array_length() {
+        const TEST_LEN: usize = name_len_of!(test_fn);
+
+        let buffer = [0u8; name_len_of!(test_field in TestStruct)];
+        let name_buffer = [0u8; TEST_LEN];
+        assert_eq!(buffer.len(), "test_field".len());
+        assert_eq!(name_buffer.len(), "test_fn".len());
+    }
+
+    #[test]
+    fn enum_name_of_unit_variant() {
+        assert_eq!(enum_name_of!(TestEnum::Red), "TestEnum");
+    }
+
+    #[test]
+    fn enum_name_of_tuple_variant() {
+        assert_eq!(enum_name_of!(TestEnum::Rgb), "TestEnum");
+        assert_eq!(enum_name_of!(TestEnum::Rgb(..)), "TestEnum");
+    }
+
+    #[test]
+    fn enum_name_of_struct_variant() {
+        assert_eq!(enum_name_of!(TestEnum::Hsl), "TestEnum");
+        assert_eq!(enum_name_of!(TestEnum::Hsl { .. }), "TestEnum");
+    }
+
+    #[test]
+    fn enum_name_of_generic_enum_variant() {
+        assert_eq!(enum_name_of!(Option::<u8>::Some(..)), "Option");
+        assert_eq!(enum_name_of!(Result::<u8, String>::Err), "Result");
+        assert_eq!(
+            enum_name_of!(TestGenericEnum::<u8>::Struct { .. }),
+            "TestGenericEnum"
+        );
+    }
+
+    #[test]
+    fn enum_name_of_qualified_variant() {
+        assert_eq!(
+            enum_name_of!(test_module::TestModuleEnum::Unit),
+            "TestModuleEnum"
+        );
+        assert_eq!(
+            enum_name_of!(self::test_module::TestModuleEnum::Tuple(..)),
+            "TestModuleEnum"
+        );
+        assert_eq!(
+            enum_name_of!(crate::tests::test_module::TestModuleEnum::Struct { .. }),
+            "TestModuleEnum"
+        );
+        assert_eq!(enum_name_of!(core::option::Option::<u8>::None), "Option");
+    }
+
+    #[test]
+    fn enum_macros_with_non_exhaustive_enum() {
+        assert_eq!(
+            enum_name_of!(TestNonExhaustiveEnum::Unit),
+            "TestNonExhaustiveEnum"
+        );
+        assert_eq!(
+            qualified_tag_of!(TestNonExhaustiveEnum::Unit),
+            "TestNonExhaustiveEnum::Unit"
+        );
+        assert_eq!(
+            qualified_tag_of!(TestNonExhaustiveEnum::Tuple(..)),
+            "TestNonExhaustiveEnum::Tuple"
+        );
+        assert_eq!(
+            qualified_tag_of!(TestNonExhaustiveEnum::Struct { .. }),
+            "TestNonExhaustiveEnum::Struct"
+        );
+        assert_eq!(name_of!(1 in TestNonExhaustiveEnum::Tuple), "1");
+        assert_eq!(
+            name_of!(test_field in TestNonExhaustiveEnum::Struct),
+            "test_field"
+        );
+        assert_eq!(
+            variants_of!(TestNonExhaustiveEnum {
+                Unit,
+                Tuple,
+                Struct
+            }),
+            ["Unit", "Tuple", "Struct"]
+        );
+    }
+
+    #[test]
+    fn tag_of_variant() {
+        assert_eq!(tag_of!(TestEnum::Red), "Red");
+        assert_eq!(tag_of!(TestEnum::Rgb(..)), "Rgb");
+        assert_eq!(tag_of!(TestEnum::Hsl { .. }), "Hsl");
+        assert_eq!(tag_of!(test_module::TestModuleEnum::Tuple(..)), "Tuple");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn tag_of_valued_variant() {
+        let (r, g, b) = (255, 128, 0);
+        assert_eq!(tag_of!(TestEnum::Rgb(r, g, b)), "Rgb(255, 128, 0)");
+        assert_eq!(tag_of!(TestEnum::Rgb(1 + 2, 0, 0,)), "Rgb(3, 0, 0)");
+        assert_eq!(
+            tag_of!(test_module::TestModuleEnum::Tuple(1, 2)),
+            "Tuple(1, 2)"
+        );
+        assert_eq!(
+            tag_of!(TestShapeEnum::Point { x: 1, y: -2 }),
+            "Point { x: 1, y: -2 }"
+        );
+        assert_eq!(
+            tag_of!(TestComplexEnum::Raw { r#type: 1 }),
+            "Raw { type: 1 }"
+        );
+        assert_eq!(
+            tag_of!(TestShapeEnum::Point {
+                x: 10 - 3,
+                y: r as i32
+            }),
+            "Point { x: 7, y: 255 }"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn tag_of_valued_variant_without_moving_values() {
+        let test_text = String::from("text");
+        assert_eq!(tag_of!(Option::Some(test_text)), "Some(\"text\")");
+        assert_eq!(test_text, "text");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn tag_of_valued_variant_with_raw_text() {
+        assert_eq!(tag_of!(Option::Some("r#x")), "Some(\"r#x\")");
+        assert_eq!(tag_of!(Option::Some(r#"a"b"#)), "Some(\"a\\\"b\")");
+        assert_eq!(
+            tag_of!(TestComplexEnum::Raw { r#type: 1 }),
+            "Raw { type: 1 }"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn tag_of_valued_struct_variant_without_moving_values() {
+        let test_text = String::from("text");
+        assert_eq!(
+            tag_of!(TestGenericEnum::<String>::Struct {
+                test_field: test_text
+            }),
+            "Struct { test_field: \"text\" }"
+        );
+        assert_eq!(test_text, "text");
+    }
+
+    #[test]
+    fn tag_of_option_and_result() {
+        assert_eq!(tag_of!(Option::<u8>::None), "None");
+        assert_eq!(tag_of!(Option::<u8>::Some(..)), "Some");
+        assert_eq!(tag_of!(Result::<u8, ()>::Err), "Err");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn tag_of_valued_option_and_result() {
+        assert_eq!(tag_of!(Result::<u8, ()>::Ok(1)), "Ok(1)");
+        assert_eq!(tag_of!(Result::<u8, ()>::Err(())), "Err(())");
+        assert_eq!(tag_of!(Option::Some(5)), "Some(5)");
+    }
+
+    #[test]
+    fn qualified_tag_of_unit_variant() {
+        assert_eq!(qualified_tag_of!(TestEnum::Red), "TestEnum::Red");
+    }
+
+    #[test]
+    fn qualified_tag_of_tuple_variant() {
+        assert_eq!(qualified_tag_of!(TestEnum::Rgb(..)), "TestEnum::Rgb");
+    }
+
+    #[test]
+    fn qualified_tag_of_struct_variant() {
+        assert_eq!(qualified_tag_of!(TestEnum::Hsl { .. }), "TestEnum::Hsl");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn qualified_tag_of_valued_variant() {
+        assert_eq!(
+            qualified_tag_of!(TestEnum::Rgb(255, 128, 0)),
+            "TestEnum::Rgb(255, 128, 0)"
+        );
+        assert_eq!(
+            qualified_tag_of!(Option::<u8>::Some(1 + 2)),
+            "Option::Some(3)"
+        );
+        assert_eq!(
+            qualified_tag_of!(test_module::TestModuleEnum::Tuple(1, 2)),
+            "TestModuleEnum::Tuple(1, 2)"
+        );
+        assert_eq!(
+            qualified_tag_of!(core::result::Result::<u8, u8>::Ok(1)),
+            "Result::Ok(1)"
+        );
+        assert_eq!(
+            qualified_tag_of!(TestGenericEnum::<i64>::Tuple(1, 2)),
+            "TestGenericEnum::Tuple(1, 2)"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn qualified_tag_of_valued_struct_variant() {
+        assert_eq!(
+            qualified_tag_of!(TestShapeEnum::Point { x: 1, y: -2 }),
+            "TestShapeEnum::Point { x: 1, y: -2 }"
+        );
+        assert_eq!(
+            qualified_tag_of!(TestEnum::Hsl {
+                h: 240,
+                s: 100,
+                l: 50
+            }),
+            "TestEnum::Hsl { h: 240, s: 100, l: 50 }"
+        );
+        assert_eq!(
+            qualified_tag_of!(TestGenericEnum::<u8>::Struct { test_field: 1 }),
+            "TestGenericEnum::Struct { test_field: 1 }"
+        );
+        assert_eq!(
+            qualified_tag_of!(TestComplexEnum::Raw { r#type: 1 }),
+            "TestComplexEnum::Raw { type: 1 }"
+        );
+    }
+
+    #[test]
+    fn qualified_tag_of_qualified_variant() {
+        assert_eq!(
+            qualified_tag_of!(test_module::TestModuleEnum::Unit),
+            "TestModuleEnum::Unit"
+        );
+        assert_eq!(
+            qualified_tag_of!(self::test_module::TestModuleEnum::Tuple(..)),
+            "TestModuleEnum::Tuple"
+        );
+        assert_eq!(
+            qualified_tag_of!(crate::tests::test_module::TestModuleEnum::Struct { .. }),
+            "TestModuleEnum::Struct"
+        );
+    }
+
+    #[test]
+    fn qualified_tag_of_option_and_result() {
+        assert_eq!(qualified_tag_of!(Option::<u8>::None), "Option::None");
+        assert_eq!(qualified_tag_of!(Option::<u8>::Some(..)), "Option::Some");
+        assert_eq!(qualified_tag_of!(Result::<u8, ()>::Err), "Result::Err");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn qualified_tag_of_valued_option_and_result() {
+        assert_eq!(qualified_tag_of!(Option::Some(5)), "Option::Some(5)");
+        assert_eq!(qualified_tag_of!(Result::<u8, ()>::Ok(1)), "Result::Ok(1)");
+        assert_eq!(
+            qualified_tag_of!(Result::<u8, ()>::Err(())),
+            "Result::Err(())"
+        );
+    }
+
+    #[test]
+    fn qualified_tag_of_generic_variant() {
+        assert_eq!(qualified_tag_of!(Option::<u8>::Some(..)), "Option::Some");
+        assert_eq!(
+            qualified_tag_of!(TestGenericEnum::<u8>::Unit),
+            "TestGenericEnum::Unit"
+        );
+        assert_eq!(
+            qualified_tag_of!(TestGenericEnum::<String>::Tuple(..)),
+            "TestGenericEnum::Tuple"
         );
+        assert_eq!(
+            qualified_tag_of!(TestGenericEnum::<Vec<u8>>::Struct { .. }),
+            "TestGenericEnum::Struct"
+        );
+    }
+
+    struct TestBuffer {
+        bytes: [u8; 40],
+        len: usize,
+    }
+
+    impl TestBuffer {
+        fn new() -> Self {
+            TestBuffer {
+                bytes: [0; 40],
+                len: 0,
+            }
+        }
+
+        fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+        }
+    }
+
+    impl core::fmt::Write for TestBuffer {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.len + s.len();
+            if end > self.bytes.len() {
+                return Err(core::fmt::Error);
+            }
+            self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
     }
 
     #[test]
-    fn name_of_generic_multi_type_struct() {
+    fn qualified_tag_of_into_buffer() {
+        let mut test_buffer = TestBuffer::new();
+        assert!(qualified_tag_of_into!(&mut test_buffer, TestEnum::Rgb(1, 2, 3)).is_ok());
+        assert!(qualified_tag_of_into!(&mut test_buffer, TestEnum::Red).is_ok());
+        assert_eq!(test_buffer.as_str(), "TestEnum::Rgb(1, 2, 3)TestEnum::Red");
+    }
+
+    #[test]
+    fn tag_of_into_buffer() {
+        let mut test_buffer = TestBuffer::new();
+        assert!(tag_of_into!(&mut test_buffer, TestEnum::Rgb(1, 2, 3)).is_ok());
+        assert!(tag_of_into!(&mut test_buffer, TestEnum::Hsl { h: 1, s: 2, l: 3 }).is_ok());
+        assert!(tag_of_into!(&mut test_buffer, TestEnum::Red).is_ok());
         assert_eq!(
-            name_of!(type TestGenericStructMultiType<i32, TestGenericStruct<String>>),
-            "TestGenericStructMultiType<i32, TestGenericStruct<String>>"
+            test_buffer.as_str(),
+            "Rgb(1, 2, 3)Hsl { h: 1, s: 2, l: 3 }Red"
+        );
+    }
+
+    #[test]
+    fn tag_of_into_full_buffer() {
+        let mut test_buffer = TestBuffer::new();
+        let test_writer: &mut dyn core::fmt::Write = &mut test_buffer;
+        assert!(tag_of_into!(test_writer, Option::Some([0u8; 16])).is_err());
+        assert!(test_buffer.as_str().starts_with("Some("));
+    }
+
+    #[test]
+    fn qualified_tag_of_into_full_buffer() {
+        let mut test_buffer = TestBuffer::new();
+        let test_writer: &mut dyn core::fmt::Write = &mut test_buffer;
+        assert!(qualified_tag_of_into!(test_writer, TestEnum::Hsl { .. }).is_ok());
+        assert!(qualified_tag_of_into!(test_writer, TestEnum::Rgb(255, 255, 255)).is_err());
+        assert!(test_buffer.as_str().starts_with("TestEnum::Hsl"));
+    }
+
+    #[test]
+    fn bytes_of_name_of_binding() {
+        let test_variable = 1;
+        assert_eq!(bytes_of_name!(test_variable), b"test_variable");
+        assert_eq!(bytes_of_name!(r#type in TestRawStruct), b"type");
+    }
+
+    #[test]
+    fn bytes_of_name_in_const() {
+        const TEST_BYTES: &[u8] = bytes_of_name!(test_field in TestStruct);
+        const TEST_FIRST_BYTE: u8 = bytes_of_name!(type TestStruct)[0];
+
+        assert_eq!(TEST_BYTES, b"test_field");
+        assert_eq!(TEST_FIRST_BYTE, b'T');
+    }
+
+    fn fnv1a_reference_hash(bytes: &[u8]) -> u64 {
+        let mut hash = 0xcbf2_9ce4_8422_2325u64;
+        for &b in bytes {
+            hash ^= u64::from(b);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+        hash
+    }
+
+    #[test]
+    fn prefixed_name_of_binding() {
+        let test_variable = 1;
+        assert_eq!(
+            prefixed_name_of!("app.", test_variable),
+            "app.test_variable"
         );
+        assert_eq!(prefixed_name_of!("", test_variable), "test_variable");
+
+        let r#type = 2;
+        assert_eq!(prefixed_name_of!("r#", r#type), "r#type");
+        assert_eq!(test_variable + r#type, 3);
+    }
 
+    #[test]
+    fn prefixed_name_of_field() {
+        const TEST_PREFIX: &str = "config.";
+        const TEST_KEY: &str = prefixed_name_of!(TEST_PREFIX, test_field in TestStruct);
+        assert_eq!(TEST_KEY, "config.test_field");
+        assert_eq!(prefixed_name_of!("tuple/", 1 in TestTupleStruct), "tuple/1");
         assert_eq!(
-            name_of_type!( TestGenericStructMultiType<i32, TestGenericStruct<String>>),
-            "TestGenericStructMultiType<i32, TestGenericStruct<String>>"
+            prefixed_name_of!("raw::", r#type in TestRawStruct),
+            "raw::type"
+        );
+        assert_eq!(
+            prefixed_name_of!("", test_field in TestStruct),
+            "test_field"
+        );
+    }
+
+    #[test]
+    fn prefixed_name_of_prefix_named_like_helper() {
+        const NAME: &str = "app.";
+        const LEN: &str = "len.";
+        let timeout = 30;
+        assert_eq!(prefixed_name_of!(NAME, timeout), "app.timeout");
+        assert_eq!(
+            prefixed_name_of!(LEN, test_field in TestStruct),
+            "len.test_field"
+        );
+        assert_eq!(timeout, 30);
+    }
+
+    #[test]
+    fn decorate_name_of_affixes_named_like_helpers() {
+        const NAME: &str = "get_";
+        const LEN: &str = "_len";
+        const BYTES: &str = "_bytes";
+        let timeout = 30;
+        assert_eq!(decorate_name_of!(NAME, timeout, LEN), "get_timeout_len");
+        assert_eq!(
+            decorate_name_of!(LEN, test_field in TestStruct, BYTES),
+            "_lentest_field_bytes"
+        );
+        assert_eq!(timeout, 30);
+    }
+
+    #[test]
+    fn decorate_name_of_binding() {
+        let test_variable = 1;
+        assert_eq!(
+            decorate_name_of!("pre_", test_variable, "_suf"),
+            "pre_test_variable_suf"
+        );
+        assert_eq!(
+            decorate_name_of!("pre_", test_variable, ""),
+            "pre_test_variable"
+        );
+        assert_eq!(
+            decorate_name_of!("", test_variable, "_suf"),
+            "test_variable_suf"
+        );
+        assert_eq!(decorate_name_of!("", test_variable, ""), "test_variable");
+    }
+
+    #[test]
+    fn decorate_name_of_field() {
+        const TEST_GETTER: &str = decorate_name_of!("get_", test_field in TestStruct, "");
+        const TEST_SETTER: &str = decorate_name_of!("set_", r#type in TestRawStruct, "_value");
+        assert_eq!(TEST_GETTER, "get_test_field");
+        assert_eq!(TEST_SETTER, "set_type_value");
+        assert_eq!(decorate_name_of!("", 0 in TestTupleStruct, "th"), "0th");
+    }
+
+    #[test]
+    fn cstr_name_of_binding() {
+        let test_variable = 1;
+        let r#type = 2;
+        assert_eq!(
+            cstr_name_of!(test_variable).to_bytes_with_nul(),
+            b"test_variable\0"
+        );
+        assert_eq!(cstr_name_of!(r#type).to_bytes_with_nul(), b"type\0");
+        assert_eq!(test_variable + r#type, 3);
+    }
+
+    #[test]
+    fn cstr_name_of_in_const() {
+        const TEST_FIELD: &core::ffi::CStr = cstr_name_of!(test_field in TestStruct);
+        const TEST_TYPE: &core::ffi::CStr = cstr_name_of!(type Vec<u8>);
+        const TEST_FN: &core::ffi::CStr = cstr_name_of!(fn test_fn);
+        assert_eq!(TEST_FIELD.to_bytes_with_nul(), b"test_field\0");
+        assert_eq!(TEST_TYPE.to_bytes_with_nul(), b"Vec<u8>\0");
+        assert_eq!(TEST_FN.to_bytes(), b"test_fn");
+    }
+
+    #[test]
+    fn cstr_name_of_type_with_length_named_like_helper() {
+        const LEN: usize = 4;
+        assert_eq!(
+            cstr_name_of!(type [u8; LEN]).to_bytes_with_nul(),
+            b"[u8; LEN]\0"
+        );
+    }
+
+    #[test]
+    fn name_token_of_comparison() {
+        let test_variable = 1;
+        let test_name = name_token_of!(test_variable);
+        assert_eq!(test_name, "test_variable");
+        assert_eq!("test_variable", test_name);
+        assert_eq!(*"test_variable", test_name);
+        assert_eq!(test_name, *"test_variable");
+        assert_eq!(test_name, name_token_of!(test_variable));
+        assert_ne!(test_name, name_token_of!(test_fn));
+        assert_eq!(test_name.as_str(), "test_variable");
+        assert_eq!(test_name.len(), 13);
+    }
+
+    #[test]
+    fn name_token_of_display() {
+        let test_name = name_token_of!(type TestStruct);
+        assert_eq!(std::format!("{}", test_name), "TestStruct");
+        assert_eq!(std::format!("{:?}", test_name), "Name(\"TestStruct\")");
+        assert_eq!(<&str>::from(test_name), "TestStruct");
+    }
+
+    #[test]
+    fn name_token_of_trait_impls() {
+        fn test_as_ref<S: AsRef<str>>(s: S) -> usize {
+            s.as_ref().len()
+        }
+
+        let test_name = name_token_of!(test_field in TestStruct);
+        assert_eq!(test_name, "test_field");
+        assert_eq!(std::format!("{}", test_name), "test_field");
+        assert_eq!(std::format!("{:?}", test_name), "Name(\"test_field\")");
+        assert_eq!(test_as_ref(test_name), 10);
+        assert!(test_name.starts_with("test_"));
+        assert_eq!(&*test_name, "test_field");
+    }
+
+    #[test]
+    fn name_token_of_in_const_and_match() {
+        const TEST_FN_NAME: super::Name = name_token_of!(test_fn);
+        const TEST_FIELD_NAME: super::Name = name_token_of!(test_field in TestStruct);
+        let test_state = name_token_of!(fn test_fn);
+        let test_result = match test_state {
+            TEST_FIELD_NAME => "field",
+            TEST_FN_NAME => "fn",
+            _ => "unknown",
+        };
+        assert_eq!(test_result, "fn");
+    }
+
+    #[test]
+    fn all_unique_names() {
+        assert!(super::__all_unique(&["test_fn", "test_fn_with_args"]));
+        assert!(super::__all_unique(&["test_fn"]));
+        assert!(!super::__all_unique(&["type", "test_fn", "type"]));
+        assert!(!super::__all_unique(&names_of!(r#test_fn, test_fn)));
+    }
+
+    #[test]
+    fn name_hash_of_binding() {
+        let a = 1;
+        let foobar = 2;
+
+        assert_eq!(name_hash_of!(a), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(name_hash_of!(foobar), 0x8594_4171_f739_67e8);
+        assert_eq!(a + foobar, 3);
+    }
+
+    #[test]
+    fn name_hash_of_matches_reference() {
+        const TEST_FIELD_HASH: u64 = name_hash_of!(test_field in TestStruct);
+
+        assert_eq!(TEST_FIELD_HASH, fnv1a_reference_hash(b"test_field"));
+        assert_eq!(
+            name_hash_of!(type TestGenericStruct<i32>),
+            fnv1a_reference_hash(b"TestGenericStruct<i32>")
+        );
+        assert_ne!(name_hash_of!(test_fn), name_hash_of!(type TestStruct));
+    }
+
+    #[test]
+    fn matches_name_of_binding() {
+        let test_variable = 1;
+        assert!(matches_name!("test_variable", test_variable));
+        assert!(!matches_name!("test_variabl", test_variable));
+    }
+
+    #[test]
+    fn matches_name_of_field() {
+        let key = String::from("test_field");
+        assert!(matches_name!(key, test_field in TestStruct));
+        assert!(!matches_name!(key, type TestStruct));
+    }
+
+    #[test]
+    fn offset_name_of_repr_c_struct_field() {
+        #[allow(dead_code)]
+        #[repr(C)]
+        struct TestReprCStruct {
+            test_byte: u8,
+            test_int: u32,
+            test_short: u16,
+        }
+
+        assert_eq!(
+            offset_name_of!(test_byte in TestReprCStruct),
+            ("test_byte", 0)
+        );
+        assert_eq!(
+            offset_name_of!(test_int in TestReprCStruct),
+            ("test_int", 4)
+        );
+        assert_eq!(
+            offset_name_of!(test_short in TestReprCStruct),
+            ("test_short", 8)
+        );
+    }
+
+    #[test]
+    fn offset_name_of_tuple_struct_field() {
+        assert_eq!(offset_name_of!(0 in TestTupleStruct).0, "0");
+        assert_ne!(
+            offset_name_of!(0 in TestTupleStruct).1,
+            offset_name_of!(1 in TestTupleStruct).1
         );
     }
 
+    #[test]
+    fn const_assert_name_in_fn() {
+        const_assert_name!(test_field in TestStruct, "test_field");
+        const_assert_name!(const TEST_CONST in TestStruct, "TEST_CONST");
+        const_assert_name!(screaming test_fn, "TEST_FN");
+    }
+
+    #[test]
+    fn name_and_value_of_copy_binding() {
+        let test_variable = 123;
+        let (name, value) = name_and_value_of!(test_variable);
+        assert_eq!(name, "test_variable");
+        assert_eq!(*value, 123);
+        assert!(std::ptr::eq(value, &test_variable));
+    }
+
+    #[test]
+    fn name_and_value_of_non_copy_binding() {
+        let test_variable = String::from("text");
+        let (name, value) = name_and_value_of!(test_variable);
+        assert_eq!(name, "test_variable");
+        assert_eq!(value, "text");
+        assert!(std::ptr::eq(value, &test_variable));
+        assert_eq!(test_variable, "text");
+    }
+
+    #[test]
+    fn names_map_of_bindings() {
+        let test_variable_x = 1;
+        let test_variable_y = 2;
+        let test_map = names_map_of!(test_variable_x, test_variable_y);
+        assert_eq!(test_map, [("test_variable_x", &1), ("test_variable_y", &2)]);
+
+        let mut test_names = Vec::new();
+        for (name, value) in test_map.iter() {
+            test_names.push(*name);
+            assert!(**value > 0);
+        }
+        assert_eq!(test_names, ["test_variable_x", "test_variable_y"]);
+    }
+
+    #[test]
+    fn names_map_of_non_copy_bindings() {
+        let test_variable_x = String::from("x");
+        let test_variable_y = String::from("y");
+        let test_map = names_map_of!(test_variable_x, test_variable_y,);
+        assert!(std::ptr::eq(test_map[0].1, &test_variable_x));
+        assert_eq!(test_map[1], ("test_variable_y", &String::from("y")));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn debug_name_of_integer() {
+        let test_variable = 42;
+        assert_eq!(debug_name_of!(test_variable), "test_variable = 42");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn debug_name_of_string() {
+        let test_variable = String::from("text");
+        assert_eq!(debug_name_of!(test_variable), "test_variable = \"text\"");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn debug_name_of_struct() {
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        struct TestDebugStruct {
+            test_field: i32,
+        }
+
+        let test_variable = TestDebugStruct { test_field: 1 };
+        assert_eq!(
+            debug_name_of!(test_variable),
+            "test_variable = TestDebugStruct { test_field: 1 }"
+        );
+    }
+
+    #[test]
+    fn type_name_of_literal() {
+        assert_eq!(type_name_of_val!(1.0f64), "f64");
+        assert_eq!(type_name_of_val!("text"), "&str");
+    }
+
+    #[test]
+    fn type_name_of_binding() {
+        let test_variable = String::from("text");
+        assert!(type_name_of_val!(test_variable).ends_with("String"));
+        assert_eq!(test_variable, "text");
+
+        let test_struct = TestStruct { test_field: 1 };
+        assert!(type_name_of_val!(test_struct).contains("TestStruct"));
+        assert_eq!(test_struct.test_field, 1);
+    }
+
+    #[test]
+    fn type_name_of_fn_call() {
+        fn make_vec() -> Vec<u8> {
+            vec![1, 2, 3]
+        }
+
+        assert!(type_name_of_val!(make_vec()).contains("Vec<u8>"));
+        assert_eq!(type_name_of_val!(test_fn_with_args(1, "text")), "bool");
+    }
+
+    #[test]
+    fn name_of_associated_type() {
+        assert_eq!(name_of!(type Item in Iterator), "Item");
+        assert_eq!(name_of!(type Target in std::ops::Deref), "Target");
+        assert_eq!(
+            name_of!(type TestAssociatedType in TestAssociatedTypeTrait),
+            "TestAssociatedType"
+        );
+    }
+
+    #[test]
+    fn name_of_associated_type_in_generic_fn() {
+        fn associated_type_names<'a, T: 'a>() -> [&'static str; 2] {
+            [
+                name_of!(type Output in std::ops::Add<T>),
+                name_of!(type IntoIter in IntoIterator<Item = &'a T>),
+            ]
+        }
+
+        assert_eq!(associated_type_names::<u8>(), ["Output", "IntoIter"]);
+    }
+
     #[test]
     fn name_of_struct_field() {
         assert_eq!(name_of!(test_field in TestStruct), "test_field");
     }
 
+    #[test]
+    fn name_of_union_field() {
+        assert_eq!(name_of!(test_int in TestUnion), "test_int");
+        assert_eq!(name_of!(test_float in TestUnion), "test_float");
+    }
+
+    #[test]
+    fn name_of_packed_struct_field() {
+        assert_eq!(name_of!(test_byte in TestPackedStruct), "test_byte");
+        assert_eq!(name_of!(test_int in TestPackedStruct), "test_int");
+    }
+
     #[test]
     fn name_of_generic_struct_field() {
         assert_eq!(name_of!(test_field in TestGenericStruct<i32>), "test_field");
@@ -235,8 +5514,155 @@ mod tests {
         );
     }
 
+    #[test]
+    fn name_of_nested_struct_field() {
+        assert_eq!(
+            name_of!(test_outer_field.test_field in TestNestedStruct<i32>),
+            "test_outer_field.test_field"
+        );
+    }
+
+    #[test]
+    fn name_of_deeply_nested_struct_field() {
+        assert_eq!(
+            name_of!(test_deep_field.test_outer_field.test_field in TestDeeplyNestedStruct),
+            "test_deep_field.test_outer_field.test_field"
+        );
+        assert_eq!(
+            name_of!(test_deep_field.test_outer_field.test_field.test_field in TestDeeplyNestedStruct),
+            "test_deep_field.test_outer_field.test_field.test_field"
+        );
+    }
+
+    #[test]
+    fn name_of_tuple_struct_field() {
+        assert_eq!(name_of!(0 in TestTupleStruct), "0");
+        assert_eq!(name_of!(1 in TestTupleStruct), "1");
+        assert_eq!(name_of!(11 in TestLargeTupleStruct), "11");
+    }
+
+    #[test]
+    fn name_of_struct_variant_field() {
+        assert_eq!(name_of!(h in TestEnum::Hsl), "h");
+        assert_eq!(name_of!(l in TestEnum::Hsl), "l");
+    }
+
+    #[test]
+    fn name_of_struct_field_in_module() {
+        assert_eq!(
+            name_of!(test_field in test_module::TestModuleStruct),
+            "test_field"
+        );
+    }
+
+    #[test]
+    fn name_of_self_field() {
+        let mut test_struct = TestStruct { test_field: 1 };
+        assert_eq!(test_struct.test_field_name(), "test_field");
+        assert_eq!(test_struct.test_field_name_mut(), "test_field");
+        assert_eq!(TestTupleStruct(1, 2).second_field_name(), "1");
+        assert_eq!(
+            TestRawStruct {
+                r#match: 1,
+                r#type: TestStruct { test_field: 2 },
+            }
+            .raw_field_name(),
+            "type"
+        );
+    }
+
+    #[test]
+    fn name_of_binding_field() {
+        let test_struct = TestStruct { test_field: 1 };
+        assert_eq!(name_of!(test_struct.test_field), "test_field");
+        assert_eq!(test_struct.test_field, 1);
+    }
+
+    #[test]
+    fn name_of_field_through_reference() {
+        assert_eq!(name_of!(test_field in &TestStruct), "test_field");
+        assert_eq!(name_of!(test_field in &mut TestStruct), "test_field");
+        assert_eq!(name_of!(test_field in &&TestStruct), "test_field");
+        assert_eq!(name_of!(0 in &TestTupleStruct), "0");
+        assert_eq!(
+            name_of!(test_outer_field.test_field in &mut TestNestedStruct<u8>),
+            "test_outer_field.test_field"
+        );
+    }
+
+    #[test]
+    fn name_of_field_through_deref() {
+        assert_eq!(name_of!(test_field in Box<TestStruct>), "test_field");
+        assert_eq!(name_of!(test_field in TestDeref<TestStruct>), "test_field");
+        assert_eq!(name_of!(1 in Box<TestTupleStruct>), "1");
+        assert_eq!(
+            name_of!(test_outer_field.test_field in TestDeref<Box<TestNestedStruct<u8>>>),
+            "test_outer_field.test_field"
+        );
+    }
+
+    #[test]
+    fn name_of_tuple_variant_field() {
+        assert_eq!(name_of!(0 in TestEnum::Rgb), "0");
+        assert_eq!(name_of!(2 in TestEnum::Rgb), "2");
+        assert_eq!(name_of!(0 in Option::<u8>::Some), "0");
+        assert_eq!(name_of!(1 in test_module::TestModuleTupleStruct), "1");
+    }
+
+    #[test]
+    fn name_of_module_constant() {
+        assert_eq!(name_of!(const TEST_MODULE_CONST), "TEST_MODULE_CONST");
+    }
+
+    #[test]
+    fn name_of_local_constant() {
+        const TEST_LOCAL_CONST: Option<String> = None;
+        assert_eq!(name_of!(const TEST_LOCAL_CONST), "TEST_LOCAL_CONST");
+    }
+
+    #[test]
+    fn name_of_trait_constant() {
+        assert_eq!(
+            name_of!(const TEST_ASSOCIATED_CONST in trait TestAssociatedConstTrait),
+            "TEST_ASSOCIATED_CONST"
+        );
+        assert_eq!(
+            name_of!(const TEST_DEFAULT_CONST in trait TestAssociatedConstTrait),
+            "TEST_DEFAULT_CONST"
+        );
+        assert_eq!(
+            name_of!(const TEST_GENERIC_CONST in trait TestGenericAssociatedConstTrait<u8>),
+            "TEST_GENERIC_CONST"
+        );
+    }
+
+    #[test]
+    fn name_of_trait_constant_in_generic_fn() {
+        fn constant_name<T>() -> &'static str {
+            name_of!(const TEST_GENERIC_CONST in trait TestGenericAssociatedConstTrait<Vec<T>>)
+        }
+
+        assert_eq!(constant_name::<u8>(), "TEST_GENERIC_CONST");
+    }
+
     #[test]
     fn name_of_struct_constant() {
         assert_eq!(name_of!(const TEST_CONST in TestStruct), "TEST_CONST");
     }
+
+    #[test]
+    fn name_of_generic_struct_constant() {
+        assert_eq!(
+            name_of!(const TEST_GENERIC_CONST in TestGenericStruct<u8>),
+            "TEST_GENERIC_CONST"
+        );
+    }
+
+    #[test]
+    fn name_of_generic_multi_type_struct_constant() {
+        assert_eq!(
+            name_of!(const TEST_GENERIC_MULTI_TYPE_CONST in TestGenericStructMultiType<u8, TestGenericStruct<String>>),
+            "TEST_GENERIC_MULTI_TYPE_CONST"
+        );
+    }
 }
@@ -0,0 +1,15 @@
+// The cases in `tests/compile_fail` must fail to compile with the diagnostics
+// recorded in their `.stderr` files. Run with `TRYBUILD=overwrite` to update them.
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile_fail/*.rs");
+}
+
+// The cases in `tests/compile_fail_no_alloc` only fail without the `alloc` feature.
+#[cfg(not(feature = "alloc"))]
+#[test]
+fn compile_fail_no_alloc() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile_fail_no_alloc/*.rs");
+}
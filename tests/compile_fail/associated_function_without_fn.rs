@@ -0,0 +1,17 @@
+#[macro_use]
+extern crate nameof;
+
+struct Counter {
+    count: u32,
+}
+
+impl Counter {
+    fn new() -> Self {
+        Counter { count: 0 }
+    }
+}
+
+fn main() {
+    println!("{}", Counter::new().count);
+    println!("{}", name_of!(new in Counter));
+}
@@ -0,0 +1,18 @@
+#[macro_use]
+extern crate nameof;
+
+fn start() {}
+fn stop() {}
+
+macro_rules! dispatch_table {
+    ($($n: ident),+) => {
+        assert_unique_names!($($n),+);
+    };
+}
+
+dispatch_table!(start, stop, r#start);
+
+fn main() {
+    start();
+    stop();
+}
@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate nameof;
+
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+fn main() {
+    println!("{}", variant_count_of!(Color { Red, Green, Blue, Blue }));
+}
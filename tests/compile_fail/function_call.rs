@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate nameof;
+
+fn greet() -> &'static str {
+    "Hello, World!"
+}
+
+fn main() {
+    println!("{} {}", name_of!(greet()), greet());
+}
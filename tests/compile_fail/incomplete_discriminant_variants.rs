@@ -0,0 +1,13 @@
+#[macro_use]
+extern crate nameof;
+
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+fn main() {
+    let color = Color::Blue;
+    println!("{}", discriminant_name_of!(color in Color { Red, Green }));
+}
@@ -0,0 +1,11 @@
+#[macro_use]
+extern crate nameof;
+
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {
+    println!("{:?}", field_names_of!(Point { x }));
+}
@@ -0,0 +1,11 @@
+#[macro_use]
+extern crate nameof;
+
+enum Color {
+    Red,
+    Hsl { h: u16, s: u8, l: u8 },
+}
+
+fn main() {
+    println!("{:?}", enum_variants_with_values_of!(Color { Red, Hsl { h, s } }));
+}
@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate nameof;
+
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+fn main() {
+    println!("{:?}", variants_of!(Color { Red, Green }));
+}
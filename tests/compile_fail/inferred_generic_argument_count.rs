@@ -0,0 +1,8 @@
+#[macro_use]
+extern crate nameof;
+
+struct Pair<A, B>(A, B);
+
+fn main() {
+    println!("{}", name_of_type!(Pair<_, _, _>));
+}
@@ -0,0 +1,6 @@
+#[macro_use]
+extern crate nameof;
+
+fn main() {
+    println!("{}", name_of!(type _));
+}
@@ -0,0 +1,7 @@
+#[macro_use]
+extern crate nameof;
+
+fn main() {
+    let text = String::new();
+    println!("{}", name_of!(text.len()));
+}
@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate nameof;
+
+struct Config {
+    verbose: bool,
+}
+
+const_assert_name!(verbose in Config, "verbosity");
+
+fn main() {}
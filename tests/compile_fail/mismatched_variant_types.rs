@@ -0,0 +1,11 @@
+#[macro_use]
+extern crate nameof;
+
+enum Color {
+    Red,
+    Rgb(u8, u8, u8),
+}
+
+fn main() {
+    println!("{:?}", enum_variants_with_values_of!(Color { Red, Rgb(u8, u8, u16) }));
+}
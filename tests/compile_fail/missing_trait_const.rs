@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate nameof;
+
+trait Bounded {
+    const MAX: u32;
+}
+
+fn main() {
+    println!("{}", name_of!(const MIN in trait Bounded));
+}
@@ -0,0 +1,11 @@
+#[macro_use]
+extern crate nameof;
+
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {
+    println!("{}", name_of!(z in Point));
+}
@@ -0,0 +1,6 @@
+#[macro_use]
+extern crate nameof;
+
+fn main() {
+    println!("{}", name_of!(fn nwe in Vec<u8>));
+}
@@ -0,0 +1,16 @@
+#[macro_use]
+extern crate nameof;
+
+struct Counter {
+    count: u32,
+}
+
+impl Counter {
+    fn count_name(&self) -> &'static str {
+        name_of!(self.cuont)
+    }
+}
+
+fn main() {
+    println!("{}", Counter { count: 0 }.count_name());
+}
@@ -0,0 +1,8 @@
+#[macro_use]
+extern crate nameof;
+
+struct TestStruct;
+
+fn main() {
+    println!("{}", name_of!(type TsetStruct));
+}
@@ -0,0 +1,11 @@
+#[macro_use]
+extern crate nameof;
+
+enum Color {
+    Red,
+    Green,
+}
+
+fn main() {
+    println!("{}", enum_name_of!(Color::Blue));
+}
@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate nameof;
+
+#[allow(dead_code)]
+enum Color {
+    Hsl { h: u16, s: u8, l: u8 },
+}
+
+fn main() {
+    let mut text = String::new();
+    let _ = qualified_tag_of_into!(&mut text, Color::Hsl { h: 240, s: 100, lum: 50 });
+}
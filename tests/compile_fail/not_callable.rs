@@ -0,0 +1,8 @@
+#[macro_use]
+extern crate nameof;
+
+struct UnitStruct;
+
+fn main() {
+    println!("{}", name_of!(fn UnitStruct));
+}
@@ -0,0 +1,11 @@
+#[macro_use]
+extern crate nameof;
+
+fn param_name(n: usize) -> &'static str {
+    let _ = n;
+    name_of!(const_param n)
+}
+
+fn main() {
+    println!("{}", param_name(4));
+}
@@ -0,0 +1,8 @@
+#[macro_use]
+extern crate nameof;
+
+fn main() {
+    let text = "Hello, World!";
+
+    println!("{}", name_of!(const text));
+}
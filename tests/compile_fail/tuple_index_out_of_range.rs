@@ -0,0 +1,8 @@
+#[macro_use]
+extern crate nameof;
+
+struct Point(i32, i32);
+
+fn main() {
+    println!("{}", name_of!(2 in Point));
+}
@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate nameof;
+
+fn lifetime_name<'a>(_: &'a str) -> &'static str {
+    name_of!(lifetime 'b)
+}
+
+fn main() {
+    println!("{}", lifetime_name("text"));
+}
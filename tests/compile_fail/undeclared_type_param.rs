@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate nameof;
+
+fn type_param_name<T>() -> &'static str {
+    name_of!(type_param U)
+}
+
+fn main() {
+    println!("{}", type_param_name::<u8>());
+}
@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate nameof;
+
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+fn main() {
+    println!("{}", variant_index_of!(Purple in Color { Red, Green, Blue }));
+}
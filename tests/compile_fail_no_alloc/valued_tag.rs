@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate nameof;
+
+enum Color {
+    Rgb(u8, u8, u8),
+}
+
+fn main() {
+    let _ = tag_of!(Color::Rgb(1, 2, 3));
+}
@@ -0,0 +1,183 @@
+#![no_std]
+
+#[macro_use]
+extern crate nameof;
+
+use nameof::FieldNames;
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[allow(dead_code)]
+struct Pair(u8, u8);
+
+#[allow(dead_code)]
+enum Shape {
+    Circle { radius: u32 },
+    Line(Point, Point),
+    Empty,
+}
+
+trait Area {
+    type Output;
+}
+
+impl Point {
+    const ORIGIN: i32 = 0;
+
+    fn length(&self) -> i32 {
+        self.x + self.y
+    }
+}
+
+impl FieldNames for Point {
+    const FIELD_NAMES: &'static [&'static str] = &field_names_of!(Point { x, y });
+}
+
+mod geometry {
+    pub mod shapes {}
+}
+
+static mut COUNTER: u8 = 0;
+
+#[used]
+#[cfg_attr(target_os = "linux", link_section = ".data")]
+static mut TICKS: u32 = 0;
+const LIMIT: usize = 4;
+
+fn generic<T>() {}
+
+fn scale(factor: i32) -> i32 {
+    factor
+}
+
+const_assert_name!(type Point, "Point");
+
+#[test]
+#[allow(non_snake_case)]
+fn nameof_bindings_work_without_std() {
+    let someValue = 1;
+    let r#type = 2;
+
+    assert_eq!(name_of!(someValue), "someValue");
+    assert_eq!(name_of!(ref someValue), "someValue");
+    assert_eq!(name_of!(mut someValue), "someValue");
+    assert_eq!(name_of!(snake someValue), "some_value");
+    assert_eq!(name_of!(kebab someValue), "some-value");
+    assert_eq!(name_of!(screaming someValue), "SOME_VALUE");
+    assert_eq!(name_of!(r#type), "type");
+    assert_eq!(name_of!(static COUNTER), "COUNTER");
+    assert_eq!(name_of!(COUNTER), "COUNTER");
+    assert_eq!(name_of!(static TICKS), "TICKS");
+    assert_eq!(name_of!(lifetime 'static), "'static");
+    assert_eq!(name_of!(macro assert), "assert");
+    assert_eq!(someValue + r#type, 3);
+}
+
+#[test]
+fn nameof_items_work_without_std() {
+    assert_eq!(name_of!(scale), "scale");
+    assert_eq!(name_of!(generic::<u8>), "generic");
+    assert_eq!(name_of!(fn scale), "scale");
+    assert_eq!(name_of!(fn length in Point), "length");
+    assert_eq!(name_of!(Point::length), "length");
+    assert_eq!(name_of!(type Point), "Point");
+    assert_eq!(
+        name_of!(type impl Iterator<Item = u8>),
+        "impl Iterator<Item = u8>"
+    );
+    assert_eq!(name_of!(type Output in Area), "Output");
+    assert_eq!(name_of!(trait Area), "Area");
+    assert_eq!(name_of!(mod geometry::shapes), "shapes");
+    assert_eq!(name_of!(mod ::core::mem), "mem");
+    assert_eq!(name_of!(const LIMIT), "LIMIT");
+    assert_eq!(name_of!(const ORIGIN in Point), "ORIGIN");
+    assert_eq!(scale(1), 1);
+}
+
+#[test]
+fn nameof_fields_work_without_std() {
+    assert_eq!(name_of!(x in Point), "x");
+    assert_eq!(name_of!(1 in Pair), "1");
+    assert_eq!(name_of!(radius in Shape::Circle), "radius");
+    assert_eq!(name_of!(0 in Shape::Line), "0");
+    assert_eq!(name_of!(x.y in Wrapper), "x.y");
+}
+
+#[test]
+fn nameof_types_work_without_std() {
+    assert_eq!(name_of_type!(&'static [u8; LIMIT]), "&'static [u8; LIMIT]");
+    assert_eq!(
+        name_of_type!(impl Iterator<Item = u8>),
+        "impl Iterator<Item = u8>"
+    );
+    assert_eq!(name_of_type!(Option<Point>), "Option<Point>");
+    assert_eq!(short_name_of_type!(Option<Point>), "Option");
+}
+
+#[test]
+fn nameof_helper_macros_work_without_std() {
+    let value = 1;
+
+    assert_eq!(full_name_of!(type Point), "nameof_no_std::Point");
+    assert_eq!(names_of!(value, scale), ["value", "scale"]);
+    assert_eq!(
+        variants_of!(Shape {
+            Circle,
+            Line,
+            Empty
+        }),
+        ["Circle", "Line", "Empty"]
+    );
+    assert_eq!(
+        variant_count_of!(Shape {
+            Circle,
+            Line,
+            Empty
+        }),
+        3
+    );
+    assert_eq!(field_names_of!(Point { x, y }), ["x", "y"]);
+    assert_eq!(all_field_names_of!(Point), ["x", "y"]);
+    assert_eq!(path_segments_of!(geometry::shapes), ["geometry", "shapes"]);
+    assert_eq!(name_len_of!(value), 5);
+    assert!(matches_name!("value", value));
+    assert_eq!(prefixed_name_of!("app.", value), "app.value");
+    assert_eq!(cstr_name_of!(value).to_bytes(), b"value");
+    assert_eq!(name_token_of!(value), "value");
+    assert_eq!(enum_name_of!(Shape::Empty), "Shape");
+    assert_eq!(qualified_tag_of!(Shape::Circle { .. }), "Shape::Circle");
+    assert_eq!(qualified_tag_of!(Shape::Line(..)), "Shape::Line");
+    assert_eq!(tag_of!(Shape::Line(..)), "Line");
+    assert_eq!(name_and_value_of!(value), ("value", &1));
+    assert_eq!(names_map_of!(value), [("value", &1)]);
+    assert_eq!(type_name_of_val!(value), "i32");
+    assert_eq!(offset_name_of!(y in Point), ("y", 4));
+}
+
+#[allow(dead_code)]
+struct Wrapper {
+    x: Point,
+}
+
+struct Counter(usize);
+
+impl core::fmt::Write for Counter {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+#[test]
+fn tag_of_into_in_no_std() {
+    let mut counter = Counter(0);
+    assert!(tag_of_into!(&mut counter, Shape::Circle { radius: 10 }).is_ok());
+    assert_eq!(counter.0, "Circle { radius: 10 }".len());
+    assert!(qualified_tag_of_into!(&mut counter, Shape::Empty).is_ok());
+    assert_eq!(counter.0, "Circle { radius: 10 }Shape::Empty".len());
+}
@@ -0,0 +1,27 @@
+#![no_std]
+
+extern crate alloc;
+
+#[macro_use]
+extern crate nameof;
+
+#[allow(dead_code)]
+enum Color {
+    Red,
+    Rgb(u8, u8, u8),
+    Hsl { h: u16, s: u8, l: u8 },
+}
+
+#[test]
+fn tag_of_with_values_in_no_std() {
+    assert_eq!(tag_of!(Color::Rgb(1, 2, 3)), "Rgb(1, 2, 3)");
+    assert_eq!(
+        qualified_tag_of!(Color::Rgb(1, 2, 3)),
+        "Color::Rgb(1, 2, 3)"
+    );
+    assert_eq!(
+        tag_of!(Color::Hsl { h: 1, s: 2, l: 3 }),
+        "Hsl { h: 1, s: 2, l: 3 }"
+    );
+    assert_eq!(tag_of!(Color::Red), "Red");
+}
@@ -1,4 +1,4 @@
-use nameof::name_of;
+use nameof::{name_of, path_segments_of};
 
 struct File {}
 
@@ -6,3 +6,70 @@ struct File {}
 fn nameof_type_works() {
     assert_eq!("File", name_of!(type File));
 }
+
+static mut COUNTER: u8 = 0;
+
+#[test]
+fn nameof_static_mut_works() {
+    assert_eq!("COUNTER", name_of!(COUNTER));
+}
+
+mod config {
+    pub mod logging {}
+}
+
+#[test]
+fn nameof_mod_works() {
+    assert_eq!("config", name_of!(mod config));
+    assert_eq!("logging", name_of!(mod crate::config::logging));
+}
+
+#[test]
+fn nameof_path_segments_works() {
+    assert_eq!(["config", "logging"], path_segments_of!(config::logging));
+    assert_eq!(["crate", "config"], path_segments_of!(crate::config));
+}
+
+struct Settings {
+    verbose: bool,
+}
+
+macro_rules! forward_name {
+    ($x: ident) => {
+        name_of!($x)
+    };
+    ($x: ident in $t: ty) => {
+        name_of!($x in $t)
+    };
+}
+
+#[test]
+fn nameof_forwarded_ident_works() {
+    let settings = Settings { verbose: true };
+    assert_eq!("settings", forward_name!(settings));
+    assert_eq!("verbose", forward_name!(verbose in Settings));
+    assert!(settings.verbose);
+}
+
+async fn fetch_settings() -> Settings {
+    Settings { verbose: false }
+}
+
+const fn default_threads() -> usize {
+    4
+}
+
+#[test]
+fn nameof_async_fn_works() {
+    assert_eq!("fetch_settings", name_of!(fetch_settings));
+    assert_eq!("fetch_settings", name_of!(fn fetch_settings));
+}
+
+#[test]
+fn nameof_const_fn_works() {
+    const NAME: &str = name_of!(fn default_threads);
+
+    assert_eq!("default_threads", name_of!(default_threads));
+    assert_eq!("default_threads", NAME);
+    assert_eq!(4, default_threads());
+}